@@ -9,8 +9,7 @@ fn main() {
 use {
     anyhow::Result,
     pubky::{Keypair, Pubky, PublicKey},
-    pubky_app_specs::{traits::HasPath, traits::Validatable, PubkyAppUser},
-    serde_json::to_vec,
+    pubky_app_specs::{traits::PutPayload, traits::Validatable, PubkyAppUser},
 };
 // Replace this with your actual homeserver public key
 
@@ -68,8 +67,9 @@ async fn main() -> Result<()> {
     // Step 5: Write the user profile to the homeserver
     println!("\nStep 5: Writing the user profile to the homeserver...");
 
-    let path = PubkyAppUser::create_path();
-    let content = to_vec(&user_profile)?;
+    let (path, content) = user_profile
+        .to_put_payload(None)
+        .map_err(anyhow::Error::msg)?;
 
     session
         .storage()
@@ -196,7 +196,7 @@ const { file, meta: fileMeta } = specsBuilder.createFile(
   "vacation-photos.pdf",
   blobMeta.url,
   "application/pdf",
-  1024
+  1024n
 );
 field("ID", fileMeta.id);
 field("Name", file.toJson().name);
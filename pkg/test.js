@@ -339,7 +339,7 @@ describe("PubkySpecs Example Objects Tests", () => {
         "Pubky adventures", 
         blobMeta.url, 
         "application/pdf", 
-        88
+        88n
       );
 
       // Test meta properties
@@ -447,7 +447,7 @@ describe("PubkySpecs Example Objects Tests", () => {
         "test-file",
         blobMeta.url,
         validMimeType,
-        100
+        100n
       );
       
       const fileJson = file.toJson();
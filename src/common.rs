@@ -1,12 +1,13 @@
 #[cfg(target_arch = "wasm32")]
 use js_sys::Date;
 
-use base32::{decode, Alphabet};
+use crate::constants::{APP_PATH, CURRENT_SCHEMA_VERSION, PROTOCOL, PUBLIC_PATH};
+use base32::{decode, encode, Alphabet};
 use url::Url;
 
 /// Returns the current timestamp in microseconds since the UNIX epoch.
 #[cfg(target_arch = "wasm32")]
-pub fn timestamp() -> i64 {
+pub fn timestamp_micros() -> i64 {
     let ms = Date::now() as i64;
     ms * 1_000
 }
@@ -14,14 +15,31 @@ pub fn timestamp() -> i64 {
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Returns the current timestamp in microseconds since the UNIX epoch.
 #[cfg(not(target_arch = "wasm32"))]
-pub fn timestamp() -> i64 {
+pub fn timestamp_micros() -> i64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap()
         .as_micros() as i64
 }
 
+/// Returns the current timestamp in milliseconds since the UNIX epoch.
+pub fn timestamp_millis() -> i64 {
+    timestamp_micros() / 1_000
+}
+
+/// Returns the current timestamp in seconds since the UNIX epoch.
+pub fn timestamp_secs() -> i64 {
+    timestamp_micros() / 1_000_000
+}
+
+/// Returns the current timestamp in microseconds since the UNIX epoch.
+#[deprecated(note = "use `timestamp_micros`, `timestamp_millis` or `timestamp_secs`")]
+pub fn timestamp() -> i64 {
+    timestamp_micros()
+}
+
 /// Trims whitespace and normalizes a URL if valid and invalid URLs are preserved
 /// (not discarded) so validation can catch them
 pub fn sanitize_url(input: &str) -> String {
@@ -32,6 +50,72 @@ pub fn sanitize_url(input: &str) -> String {
     }
 }
 
+/// Like `sanitize_url`, but additionally repairs pubky URIs missing their
+/// scheme: an input such as `<user_id>/pub/pubky.app/posts/<id>` becomes
+/// `pubky://<user_id>/pub/pubky.app/posts/<id>`. Anything else that fails to
+/// parse is preserved (trimmed) so validation can catch it.
+pub fn sanitize_pubky_uri(input: &str) -> String {
+    let trimmed = input.trim();
+    if let Ok(parsed_url) = Url::parse(trimmed) {
+        return parsed_url.to_string();
+    }
+
+    let app_prefix = [PUBLIC_PATH.trim_start_matches('/'), APP_PATH].concat();
+    let looks_like_pubky_path = trimmed.split_once('/').is_some_and(|(host, path)| {
+        !host.is_empty() && !host.contains(':') && path.starts_with(&app_prefix)
+    });
+    if looks_like_pubky_path {
+        if let Ok(parsed_url) = Url::parse(&[PROTOCOL, trimmed].concat()) {
+            return parsed_url.to_string();
+        }
+    }
+
+    trimmed.to_string()
+}
+
+/// Returns `true` for invisible characters that can be abused to spoof text:
+/// bidi embeddings, overrides and isolates (U+202A–U+202E, U+2066–U+2069),
+/// zero-width space (U+200B), word joiner (U+2060) and BOM (U+FEFF).
+///
+/// Zero-width (non-)joiners and the LRM/RLM marks are allowed, since emoji
+/// sequences and right-to-left scripts legitimately rely on them.
+pub fn is_disallowed_invisible_char(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200B}' | '\u{202A}'..='\u{202E}' | '\u{2060}' | '\u{2066}'..='\u{2069}' | '\u{FEFF}'
+    )
+}
+
+/// Removes every character matched by `is_disallowed_invisible_char`.
+pub fn strip_disallowed_invisible_chars(input: &str) -> String {
+    input
+        .chars()
+        .filter(|c| !is_disallowed_invisible_char(*c))
+        .collect()
+}
+
+/// Returns `true` for control characters (C0, DEL and C1) other than `\n` and
+/// `\t`, such as NUL, which can corrupt downstream rendering and storage.
+pub fn is_disallowed_control_char(c: char) -> bool {
+    c.is_control() && !matches!(c, '\n' | '\t')
+}
+
+/// Removes every character matched by `is_disallowed_control_char`.
+pub fn strip_disallowed_control_chars(input: &str) -> String {
+    input
+        .chars()
+        .filter(|c| !is_disallowed_control_char(*c))
+        .collect()
+}
+
+/// Converts `\r\n` and lone `\r` line endings to `\n`.
+///
+/// Must run before `strip_disallowed_control_chars`, which would otherwise
+/// drop a lone `\r` and join the lines it separated.
+pub fn normalize_line_endings(input: &str) -> String {
+    input.replace("\r\n", "\n").replace('\r', "\n")
+}
+
 /// Validates structural correctness of a Crockford Base32-encoded ID (13
 /// characters, decodes to 8 bytes). Returns the decoded bytes on success.
 ///
@@ -54,6 +138,52 @@ pub fn validate_crockford_id(id: &str) -> Result<[u8; 8], String> {
     Ok(decoded_bytes.try_into().unwrap())
 }
 
+/// Hashes `data` with Blake3 and encodes the first half of the hash bytes in
+/// Crockford Base32. This is the format of all content-addressed ids.
+pub(crate) fn blake3_crockford_id(data: &[u8]) -> String {
+    let blake3_hash = blake3::hash(data);
+
+    // Get the first half of the hash bytes
+    let half_hash_length = blake3_hash.as_bytes().len() / 2;
+    let half_hash = &blake3_hash.as_bytes()[..half_hash_length];
+
+    encode(Alphabet::Crockford, half_hash)
+}
+
+/// Earliest accepted timestamp: October 1st, 2024, in microseconds since UNIX epoch.
+pub(crate) const MIN_TIMESTAMP_MICROS: i64 = 1727740800000000; // 2024-10-01 00:00:00 UTC
+
+/// Allowable clock skew into the future (2 hours) in microseconds.
+pub(crate) const MAX_FUTURE_MICROS: i64 = 2 * 60 * 60 * 1_000_000;
+
+/// Validates that a `created_at` timestamp (in microseconds) lies within the
+/// same window accepted for timestamp IDs: after October 1st, 2024 and no more
+/// than 2 hours in the future.
+pub fn validate_created_at(created_at: i64) -> Result<(), String> {
+    if created_at < MIN_TIMESTAMP_MICROS {
+        return Err("Validation Error: created_at must be after October 1st, 2024".into());
+    }
+    if created_at > timestamp_micros() + MAX_FUTURE_MICROS {
+        return Err("Validation Error: created_at is too far in the future".into());
+    }
+    Ok(())
+}
+
+/// Validates that an object's `schema_version` is supported by this crate.
+///
+/// A missing version is treated as version 1. Versions newer than
+/// `CURRENT_SCHEMA_VERSION` are rejected, since fields introduced by a newer
+/// schema cannot be validated here.
+pub fn validate_schema_version(version: Option<u16>) -> Result<(), String> {
+    match version {
+        Some(0) => Err("Validation Error: Schema version must be at least 1".into()),
+        Some(v) if v > CURRENT_SCHEMA_VERSION => Err(format!(
+            "Validation Error: Unsupported schema version {v} (max supported: {CURRENT_SCHEMA_VERSION})"
+        )),
+        _ => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,4 +203,89 @@ mod tests {
     fn invalid_id_fails() {
         assert!(validate_crockford_id("UUUUUUUUUUUUU").is_err());
     }
+
+    #[test]
+    fn sanitize_pubky_uri_adds_missing_scheme() {
+        assert_eq!(
+            sanitize_pubky_uri(" user_id/pub/pubky.app/posts/0032SSN7Q4EVG "),
+            "pubky://user_id/pub/pubky.app/posts/0032SSN7Q4EVG"
+        );
+        // Already valid URIs are only normalized
+        assert_eq!(
+            sanitize_pubky_uri("https://example.com"),
+            "https://example.com/"
+        );
+        // Anything else is preserved for validation to reject
+        assert_eq!(sanitize_pubky_uri("invalid_uri"), "invalid_uri");
+        assert_eq!(
+            sanitize_pubky_uri("user_id/other/path"),
+            "user_id/other/path"
+        );
+    }
+
+    #[test]
+    fn strip_invisible_chars() {
+        assert_eq!(
+            strip_disallowed_invisible_chars("abc\u{202E}fed\u{200B}\u{FEFF}"),
+            "abcfed"
+        );
+        // Joiners used by emoji sequences are preserved
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(strip_disallowed_invisible_chars(family), family);
+    }
+
+    #[test]
+    fn normalize_line_endings_to_lf() {
+        assert_eq!(normalize_line_endings("a\r\nb\rc\nd"), "a\nb\nc\nd");
+        assert_eq!(normalize_line_endings("\r\r\n"), "\n\n");
+    }
+
+    #[test]
+    fn strip_control_chars() {
+        assert_eq!(
+            strip_disallowed_control_chars("a\0b\u{7}c\u{7F}d\u{85}e"),
+            "abcde"
+        );
+        assert_eq!(
+            strip_disallowed_control_chars("line\n\tindented"),
+            "line\n\tindented"
+        );
+    }
+
+    #[test]
+    fn timestamp_units_are_consistent() {
+        let micros = timestamp_micros();
+        let millis = timestamp_millis();
+        let secs = timestamp_secs();
+        // Allow for the clock ticking between calls
+        assert!(millis - micros / 1_000 <= 1_000);
+        assert!(millis >= micros / 1_000);
+        assert!(secs - millis / 1_000 <= 1);
+        assert!(secs >= millis / 1_000);
+        #[allow(deprecated)]
+        let legacy = timestamp();
+        assert!(legacy >= micros);
+    }
+
+    #[test]
+    fn created_at_bounds() {
+        assert!(validate_created_at(timestamp_micros()).is_ok());
+        assert!(validate_created_at(MIN_TIMESTAMP_MICROS).is_ok());
+        assert!(validate_created_at(-1).is_err());
+        assert!(validate_created_at(MIN_TIMESTAMP_MICROS - 1).is_err());
+        assert!(validate_created_at(timestamp_micros() + 3 * 60 * 60 * 1_000_000).is_err());
+    }
+
+    #[test]
+    fn schema_version_missing_is_v1() {
+        assert!(validate_schema_version(None).is_ok());
+        assert!(validate_schema_version(Some(1)).is_ok());
+    }
+
+    #[test]
+    fn schema_version_future_fails() {
+        let result = validate_schema_version(Some(CURRENT_SCHEMA_VERSION + 1));
+        assert!(result.unwrap_err().contains("Unsupported schema version"));
+        assert!(validate_schema_version(Some(0)).is_err());
+    }
 }
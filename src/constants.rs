@@ -5,3 +5,7 @@ pub static VERSION: &str = "0.5.0";
 pub static PUBLIC_PATH: &str = "/pub/";
 pub static APP_PATH: &str = "pubky.app/";
 pub static PROTOCOL: &str = "pubky://";
+
+// Schema version stamped on objects that carry a `schema_version` field.
+// Objects without the field are treated as version 1.
+pub const CURRENT_SCHEMA_VERSION: u16 = 1;
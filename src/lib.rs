@@ -8,27 +8,34 @@ mod uri_parser;
 mod utils;
 
 // Re-export constants
-pub use constants::{APP_PATH, PROTOCOL, PUBLIC_PATH, VERSION};
+pub use constants::{APP_PATH, CURRENT_SCHEMA_VERSION, PROTOCOL, PUBLIC_PATH, VERSION};
 // Re-export common utilities
-pub use common::validate_crockford_id;
+#[allow(deprecated)]
+pub use common::{
+    timestamp, timestamp_micros, timestamp_millis, timestamp_secs, validate_created_at,
+    validate_crockford_id, validate_schema_version,
+};
 #[doc(inline)]
 pub use limits::*;
 // Re-export domain types
 pub use models::blob::PubkyAppBlob;
 pub use models::bookmark::PubkyAppBookmark;
-pub use models::feed::{PubkyAppFeed, PubkyAppFeedLayout, PubkyAppFeedReach, PubkyAppFeedSort};
-pub use models::file::{PubkyAppFile, VALID_MIME_TYPES};
+pub use models::feed::{
+    PubkyAppFeed, PubkyAppFeedConfig, PubkyAppFeedLayout, PubkyAppFeedReach, PubkyAppFeedSort,
+    PubkyAppFeedTagMatch,
+};
+pub use models::file::{PubkyAppFile, PubkyAppFileSourceKind, VALID_MIME_TYPES};
 pub use models::follow::PubkyAppFollow;
 pub use models::last_read::PubkyAppLastRead;
 pub use models::mute::PubkyAppMute;
 pub use models::post::{
     PubkyAppCollectionContent, PubkyAppPost, PubkyAppPostEmbed, PubkyAppPostKind,
 };
-pub use models::tag::PubkyAppTag;
-pub use models::user::{PubkyAppUser, PubkyAppUserLink};
+pub use models::tag::{count_labels, labels_for_uri, sanitize_tag_label_aggressive, PubkyAppTag};
+pub use models::user::{PubkyAppUser, PubkyAppUserLink, PubkyAppUserPatch};
 pub use models::PubkyAppObject;
 pub use types::PubkyId;
-pub use uri_parser::{ParsedUri, Resource};
+pub use uri_parser::{ParsedUri, Resource, ResourceKind};
 pub use utils::*;
 
 // Our WASM module
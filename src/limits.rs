@@ -29,12 +29,20 @@ pub struct ValidationLimits {
     pub tag_label_min_length: usize,
     /// Maximum number of characters for tag labels.
     pub tag_label_max_length: usize,
+    /// Maximum length of a tagged URI in characters.
+    ///
+    /// Bounds the hashed id input and the stored object size.
+    pub tag_uri_max_length: usize,
     /// Disallowed characters, including common whitespace.
     pub tag_invalid_chars: &'static [char],
     /// Minimum username length in characters.
     pub user_name_min_length: usize,
     /// Maximum username length in characters.
     pub user_name_max_length: usize,
+    /// Names reserved for official accounts, rejected case-insensitively by
+    /// `PubkyAppUser::validate_strict`. Excludes `anonymous`, the name
+    /// `sanitize` gives deleted profiles.
+    pub user_reserved_names: &'static [&'static str],
     /// Maximum bio length in characters.
     pub user_bio_max_length: usize,
     /// Maximum image URL length in characters.
@@ -45,16 +53,35 @@ pub struct ValidationLimits {
     pub user_link_title_max_length: usize,
     /// Maximum link URL length in characters.
     pub user_link_url_max_length: usize,
+    /// Maximum combined size in bytes (UTF-8) of all link titles and URLs.
+    ///
+    /// Keeps a profile with every link near its individual limits from
+    /// bloating the profile object.
+    pub user_links_max_total_bytes: usize,
     /// Maximum status length in characters.
     pub user_status_max_length: usize,
     /// Maximum character count for short posts.
     pub post_short_content_max_length: usize,
     /// Maximum character count for long posts.
     pub post_long_content_max_length: usize,
+    /// Maximum character count for the caption of Image, Video, Link and
+    /// File posts.
+    pub post_media_caption_max_length: usize,
+    /// Maximum post content size in bytes (UTF-8), for any kind.
+    ///
+    /// Character limits are for UX; homeserver storage is byte-based, and a
+    /// long post of 50k 4-byte emoji would otherwise weigh 200 KB.
+    pub post_content_max_bytes: usize,
     /// Maximum number of attachments per post.
     pub post_attachments_max_count: usize,
-    /// Maximum length for attachment URLs.
+    /// Maximum length for `pubky://` attachment URLs, which are short
+    /// homeserver references. Also bounds a Collection `cover_image`.
     pub post_attachment_url_max_length: usize,
+    /// Maximum length for `http://` and `https://` attachment URLs, which can
+    /// legitimately carry long paths and query strings.
+    pub post_attachment_http_url_max_length: usize,
+    /// Maximum number of explicitly mentioned users per post.
+    pub post_mentions_max_count: usize,
     /// Allowed protocols for attachment URLs.
     pub post_allowed_attachment_protocols: &'static [&'static str],
     /// Maximum scalar count (`chars().count()`, not bytes) for the JSON
@@ -89,19 +116,33 @@ pub const VALIDATION_LIMITS: ValidationLimits = ValidationLimits {
     max_file_size_bytes: 100 * (1 << 20), // Kept in sync with blob validation.
     tag_label_min_length: 1,
     tag_label_max_length: 20,
+    tag_uri_max_length: 1024,
     tag_invalid_chars: &[',', ':', ' ', '\t', '\n', '\r'],
     user_name_min_length: 3,
     user_name_max_length: 50,
+    user_reserved_names: &[
+        "admin",
+        "administrator",
+        "moderator",
+        "root",
+        "support",
+        "system",
+    ],
     user_bio_max_length: 160,
     user_image_url_max_length: 300,
     user_links_max_count: 5,
     user_link_title_max_length: 100,
     user_link_url_max_length: 300,
+    user_links_max_total_bytes: 1500,
     user_status_max_length: 50,
     post_short_content_max_length: 2000,
     post_long_content_max_length: 50_000,
+    post_media_caption_max_length: 5000,
+    post_content_max_bytes: 150_000,
     post_attachments_max_count: 10,
     post_attachment_url_max_length: 200,
+    post_attachment_http_url_max_length: 2048,
+    post_mentions_max_count: 50,
     post_allowed_attachment_protocols: &["pubky", "http", "https"],
     collection_content_max_length: 40_000,
     collection_name_min_length: 1,
@@ -1,10 +1,10 @@
 use crate::{
+    common::blake3_crockford_id,
     limits::VALIDATION_LIMITS,
-    traits::{HasIdPath, HashId, Validatable},
-    APP_PATH, PUBLIC_PATH,
+    traits::{HasIdPath, HashId, PutPayload, ResourceModel, Validatable},
+    ResourceKind, APP_PATH, PUBLIC_PATH,
 };
-use base32::{encode, Alphabet};
-use blake3::Hasher;
+use base64::{engine::general_purpose::STANDARD, Engine};
 use serde::{Deserialize, Serialize};
 
 #[cfg(target_arch = "wasm32")]
@@ -18,7 +18,7 @@ use utoipa::ToSchema;
 /// Represents a blob, which backs a file uploaded by the user.
 /// URI: /pub/pubky.app/blobs/:blob_id
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
-#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+#[derive(Deserialize, Serialize, Default, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct PubkyAppBlob(#[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))] pub Vec<u8>);
 
@@ -27,6 +27,79 @@ impl PubkyAppBlob {
     pub fn new(data: Vec<u8>) -> Self {
         Self(data)
     }
+
+    /// Creates a new `PubkyAppBlob`, rejecting empty or oversized data up front.
+    pub fn try_new(data: Vec<u8>) -> Result<Self, String> {
+        let blob = Self(data);
+        blob.validate(None)?;
+        Ok(blob)
+    }
+
+    /// Decodes standard (padded) base64 into a validated `PubkyAppBlob`.
+    pub fn from_base64(encoded: &str) -> Result<Self, String> {
+        let data = STANDARD
+            .decode(encoded.trim())
+            .map_err(|e| format!("Validation Error: Invalid base64 blob data: {}", e))?;
+        Self::try_new(data)
+    }
+
+    /// Encodes the blob data as standard (padded) base64.
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(&self.0)
+    }
+
+    /// Returns the blob data as a byte slice.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the blob size in bytes.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the blob holds no data.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Guesses the MIME type from the blob's magic bytes, for blobs that
+    /// arrive without file metadata. Returns `None` if the format is not
+    /// recognized (plain text has no signature).
+    #[cfg(feature = "infer")]
+    pub fn guess_content_type(&self) -> Option<String> {
+        infer::get(&self.0).map(|kind| kind.mime_type().to_string())
+    }
+}
+
+impl std::ops::Deref for PubkyAppBlob {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Prints the size and id instead of the raw bytes, which would flood logs
+/// and test failure output for large blobs.
+impl std::fmt::Debug for PubkyAppBlob {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PubkyAppBlob(<{} bytes, id={}>)",
+            self.0.len(),
+            self.create_id()
+        )
+    }
+}
+
+impl IntoIterator for PubkyAppBlob {
+    type Item = u8;
+    type IntoIter = std::vec::IntoIter<u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -59,17 +132,8 @@ impl HashId for PubkyAppBlob {
     }
 
     fn create_id(&self) -> String {
-        // Create a Blake3 hash of the blob data
-        let mut hasher = Hasher::new();
-        hasher.update(&self.0);
-        let blake3_hash = hasher.finalize();
-
-        // Get the first half of the hash bytes
-        let half_hash_length = blake3_hash.as_bytes().len() / 2;
-        let half_hash = &blake3_hash.as_bytes()[..half_hash_length];
-
-        // Encode the first half of the hash in Base32 using the Z-base32 alphabet
-        encode(Alphabet::Crockford, half_hash)
+        // Hash the entire blob data
+        blake3_crockford_id(&self.0)
     }
 }
 
@@ -81,6 +145,23 @@ impl HasIdPath for PubkyAppBlob {
     }
 }
 
+impl PutPayload for PubkyAppBlob {
+    fn put_path(&self, id: Option<&str>) -> Result<String, String> {
+        let id = id.map_or_else(|| self.create_id(), String::from);
+        Ok(Self::create_path(&id))
+    }
+
+    /// Like the default, but the body is the raw blob bytes, not JSON.
+    fn to_put_payload(&self, id: Option<&str>) -> Result<(String, Vec<u8>), String> {
+        self.validate(id)?;
+        Ok((self.put_path(id)?, self.0.clone()))
+    }
+}
+
+impl ResourceModel for PubkyAppBlob {
+    const KIND: ResourceKind = ResourceKind::Blob;
+}
+
 impl Validatable for PubkyAppBlob {
     fn try_from(blob: &[u8], id: &str) -> Result<Self, String> {
         let instance = Self(blob.to_vec());
@@ -88,6 +169,23 @@ impl Validatable for PubkyAppBlob {
         Ok(instance)
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    fn try_from_reader<R: std::io::Read>(mut reader: R, id: &str) -> Result<Self, String> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).map_err(|e| e.to_string())?;
+        <Self as Validatable>::try_from(&data, id)
+    }
+
+    fn is_valid(blob: &[u8], id: &str) -> bool {
+        Self(blob.to_vec()).validate(Some(id)).is_ok()
+    }
+
+    fn try_from_lenient(blob: &[u8], id: &str) -> Result<(Self, Vec<String>), String> {
+        let instance = Self(blob.to_vec());
+        let notes = instance.validate(Some(id)).err().into_iter().collect();
+        Ok((instance, notes))
+    }
+
     fn validate(&self, id: Option<&str>) -> Result<(), String> {
         // Check if the blob data is empty or exceeds maximum size
         if self.0.is_empty() {
@@ -161,6 +259,34 @@ mod tests {
         assert!(result.unwrap_err().contains("exceeds maximum limit"));
     }
 
+    #[test]
+    fn test_try_new() {
+        let blob = PubkyAppBlob::try_new(vec![1, 2, 3]).unwrap();
+        assert_eq!(blob.as_slice(), &[1, 2, 3]);
+
+        let err = PubkyAppBlob::try_new(Vec::new()).unwrap_err();
+        assert!(err.contains("cannot be zero"));
+
+        let err =
+            PubkyAppBlob::try_new(vec![0; VALIDATION_LIMITS.max_blob_size_bytes + 1]).unwrap_err();
+        assert!(err.contains("exceeds maximum limit"));
+    }
+
+    #[test]
+    fn test_base64_round_trip() {
+        let blob = PubkyAppBlob::new(b"hello pubky".to_vec());
+        let encoded = blob.to_base64();
+        assert_eq!(encoded, "aGVsbG8gcHVia3k=");
+
+        let decoded = PubkyAppBlob::from_base64(&encoded).unwrap();
+        assert_eq!(decoded.as_slice(), blob.as_slice());
+        assert_eq!(decoded.create_id(), blob.create_id());
+
+        let err = PubkyAppBlob::from_base64("not base64!").unwrap_err();
+        assert!(err.contains("Invalid base64"));
+        assert!(PubkyAppBlob::from_base64("").is_err());
+    }
+
     #[test]
     fn test_validate_invalid_id() {
         let blob = PubkyAppBlob(vec![1, 2, 3]);
@@ -188,4 +314,44 @@ mod tests {
         let result = <PubkyAppBlob as Validatable>::try_from(&blob_data, invalid_id);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_byte_helpers() {
+        let blob = PubkyAppBlob::new(vec![1, 2, 3]);
+        assert_eq!(blob.len(), 3);
+        assert!(!blob.is_empty());
+        assert_eq!(blob.as_slice(), &[1, 2, 3]);
+        assert_eq!(blob[1], 2);
+        assert_eq!(&blob[1..], &[2, 3]);
+        assert_eq!(blob.into_iter().sum::<u8>(), 6);
+
+        assert!(PubkyAppBlob::default().is_empty());
+    }
+
+    #[test]
+    fn test_debug_is_concise() {
+        let blob = PubkyAppBlob::new(vec![7; 1024 * 1024]);
+        let debug = format!("{:?}", blob);
+        assert_eq!(
+            debug,
+            format!("PubkyAppBlob(<1048576 bytes, id={}>)", blob.create_id())
+        );
+
+        assert_eq!(blob, PubkyAppBlob::new(vec![7; 1024 * 1024]));
+        assert_ne!(blob, PubkyAppBlob::new(vec![7; 10]));
+    }
+
+    #[cfg(feature = "infer")]
+    #[test]
+    fn test_guess_content_type() {
+        let png = PubkyAppBlob::new(vec![
+            0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D,
+        ]);
+        assert_eq!(png.guess_content_type().as_deref(), Some("image/png"));
+
+        let text = PubkyAppBlob::new(b"just some plain text".to_vec());
+        assert_eq!(text.guess_content_type(), None);
+
+        assert_eq!(PubkyAppBlob::default().guess_content_type(), None);
+    }
 }
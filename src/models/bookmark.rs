@@ -1,7 +1,7 @@
 use crate::{
-    common::timestamp,
-    traits::{HasIdPath, HashId, Validatable},
-    APP_PATH, PUBLIC_PATH,
+    common::{sanitize_pubky_uri, timestamp_micros},
+    traits::{HasIdPath, HashId, PutPayload, ResourceModel, Validatable},
+    ParsedUri, PubkyAppPost, Resource, ResourceKind, APP_PATH, PUBLIC_PATH,
 };
 use serde::{Deserialize, Serialize};
 use url::Url;
@@ -30,13 +30,39 @@ pub struct PubkyAppBookmark {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
     pub uri: String,
     pub created_at: i64,
+    /// Whether the bookmark is private. A missing value means public.
+    ///
+    /// Private bookmarks live outside the public `/pub/` path, which is the
+    /// only path this crate builds, so `validate` rejects them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub private: Option<bool>,
 }
 
 impl PubkyAppBookmark {
     /// Creates a new `PubkyAppBookmark` instance.
     pub fn new(uri: String) -> Self {
-        let created_at = timestamp();
-        Self { uri, created_at }.sanitize()
+        let created_at = timestamp_micros();
+        Self {
+            uri,
+            created_at,
+            private: None,
+        }
+        .sanitize()
+    }
+
+    /// Returns `true` if the bookmark is marked private.
+    pub fn is_private(&self) -> bool {
+        self.private.unwrap_or(false)
+    }
+
+    /// Returns `true` if the bookmark is on a post and `target`, the post fetched
+    /// from its `uri`, is the `[DELETED]` tombstone. Clients can use it to hide
+    /// bookmarks orphaned by a deletion.
+    pub fn points_at_deleted(&self, target: &PubkyAppPost) -> bool {
+        let on_post = ParsedUri::try_from(self.uri.as_str())
+            .is_ok_and(|uri| matches!(uri.resource, Resource::Post(_)));
+        on_post && target.is_deleted()
     }
 }
 
@@ -59,6 +85,12 @@ impl PubkyAppBookmark {
     pub fn uri(&self) -> String {
         self.uri.clone()
     }
+
+    /// Getter for `private`.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter))]
+    pub fn private(&self) -> Option<bool> {
+        self.private
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -79,13 +111,41 @@ impl HasIdPath for PubkyAppBookmark {
     }
 }
 
+impl PutPayload for PubkyAppBookmark {
+    fn put_path(&self, id: Option<&str>) -> Result<String, String> {
+        let id = id.map_or_else(|| self.create_id(), String::from);
+        Ok(Self::create_path(&id))
+    }
+}
+
+impl ResourceModel for PubkyAppBookmark {
+    const KIND: ResourceKind = ResourceKind::Bookmark;
+}
+
 impl Validatable for PubkyAppBookmark {
+    fn sanitize(self) -> Self {
+        // Sanitize URI: normalize it, adding the `pubky://` scheme if it is
+        // missing. Invalid URIs are kept as-is for error reporting later.
+        let uri = sanitize_pubky_uri(&self.uri);
+
+        PubkyAppBookmark {
+            uri,
+            created_at: self.created_at,
+            private: self.private,
+        }
+    }
+
     fn validate(&self, id: Option<&str>) -> Result<(), String> {
         // Validate the bookmark ID
         if let Some(id) = id {
             self.validate_id(id)?;
         }
-        // Additional bookmark validation can be added here.
+
+        if self.is_private() {
+            return Err(
+                "Validation Error: Private bookmarks must not be written to the public path".into(),
+            );
+        }
 
         // Validate URI format
         Url::parse(&self.uri)
@@ -99,11 +159,35 @@ mod tests {
     use super::*;
     use crate::{post_uri_builder, traits::Validatable};
 
+    #[test]
+    fn test_points_at_deleted() {
+        let post_uri = post_uri_builder(
+            "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".into(),
+            "0032SSN7Q4EVG".into(),
+        );
+        let tombstone = PubkyAppPost {
+            content: "[DELETED]".to_string(),
+            ..PubkyAppPost::empty_short()
+        };
+        let live = PubkyAppPost {
+            content: "Hello".to_string(),
+            ..PubkyAppPost::empty_short()
+        };
+
+        let bookmark = PubkyAppBookmark::new(post_uri.clone());
+        assert!(bookmark.points_at_deleted(&tombstone));
+        assert!(!bookmark.points_at_deleted(&live));
+
+        let on_url = PubkyAppBookmark::new("https://example.com".to_string());
+        assert!(!on_url.points_at_deleted(&tombstone));
+    }
+
     #[test]
     fn test_create_bookmark_id() {
         let bookmark = PubkyAppBookmark {
             uri: post_uri_builder("user_id".into(), "post_id".into()),
             created_at: 1627849723,
+            private: None,
         };
 
         let bookmark_id = bookmark.create_id();
@@ -116,6 +200,7 @@ mod tests {
         let bookmark = PubkyAppBookmark {
             uri: post_uri,
             created_at: 1627849723,
+            private: None,
         };
         let expected_id = bookmark.create_id();
         let expected_path = format!("{}{}bookmarks/{}", PUBLIC_PATH, APP_PATH, expected_id);
@@ -132,6 +217,37 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_private_bookmark() {
+        let post_uri = post_uri_builder(
+            "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".into(),
+            "0032SSN7Q4EVG".into(),
+        );
+        let public = PubkyAppBookmark::new(post_uri);
+        assert!(!public.is_private());
+        assert!(!serde_json::to_string(&public).unwrap().contains("private"));
+
+        let private = PubkyAppBookmark {
+            private: Some(true),
+            ..public.clone()
+        };
+        assert!(private.is_private());
+        // The id only hashes the URI
+        assert_eq!(private.create_id(), public.create_id());
+        assert!(private
+            .validate(Some(&private.create_id()))
+            .unwrap_err()
+            .contains("public path"));
+
+        let explicit_public = PubkyAppBookmark {
+            private: Some(false),
+            ..public
+        };
+        assert!(explicit_public
+            .validate(Some(&explicit_public.create_id()))
+            .is_ok());
+    }
+
     #[test]
     fn test_validate_invalid_id() {
         let post_uri = post_uri_builder("user_id".into(), "post_id".into());
@@ -143,7 +259,7 @@ mod tests {
 
     #[test]
     fn test_validate_invalid_uri() {
-        let post_uri = "user_id/pub/pubky.app/posts/post_id".to_string();
+        let post_uri = "not a uri/at all".to_string();
         let bookmark = PubkyAppBookmark::new(post_uri);
 
         let id = bookmark.create_id();
@@ -171,4 +287,14 @@ mod tests {
 
         assert_eq!(bookmark_parsed.uri, uri);
     }
+
+    #[test]
+    fn test_sanitize_adds_missing_pubky_scheme() {
+        let bookmark = PubkyAppBookmark::new("user_id/pub/pubky.app/posts/post_id".to_string());
+        assert_eq!(
+            bookmark.uri,
+            post_uri_builder("user_id".into(), "post_id".into())
+        );
+        assert!(bookmark.validate(Some(&bookmark.create_id())).is_ok());
+    }
 }
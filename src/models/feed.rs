@@ -1,12 +1,12 @@
 use crate::{
-    common::timestamp,
+    common::timestamp_micros,
     limits::VALIDATION_LIMITS,
     models::tag::{sanitize_tag_label, validate_tag_label},
-    traits::{HasIdPath, HashId, Validatable},
-    PubkyAppPostKind, APP_PATH, PUBLIC_PATH,
+    traits::{HasIdPath, HashId, PutPayload, ResourceModel, Validatable},
+    PubkyAppPost, PubkyAppPostKind, ResourceKind, APP_PATH, PUBLIC_PATH,
 };
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
+use std::{collections::BTreeMap, str::FromStr};
 
 #[cfg(target_arch = "wasm32")]
 use crate::traits::Json;
@@ -21,6 +21,8 @@ use utoipa::ToSchema;
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub enum PubkyAppFeedReach {
     Following,
     Followers,
@@ -33,6 +35,8 @@ pub enum PubkyAppFeedReach {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub enum PubkyAppFeedLayout {
     Columns,
     Wide,
@@ -45,15 +49,33 @@ pub enum PubkyAppFeedLayout {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub enum PubkyAppFeedSort {
     Recent,
     Popularity,
 }
 
+/// Enum representing how the feed tags are matched against a post's tags.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub enum PubkyAppFeedTagMatch {
+    /// The post must have at least one of the feed tags.
+    #[default]
+    Any,
+    /// The post must have every feed tag.
+    All,
+}
+
 /// Configuration object for the feed.
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct PubkyAppFeedConfig {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
     pub tags: Option<Vec<String>>,
@@ -63,8 +85,26 @@ pub struct PubkyAppFeedConfig {
     pub layout: PubkyAppFeedLayout,
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
     pub sort: PubkyAppFeedSort,
+    /// Post kinds shown in the feed; `None` shows every kind.
+    ///
+    /// A single kind is serialized as a plain string, as before multi-kind
+    /// feeds existed, so existing feed ids are unchanged. Feeds with several
+    /// kinds serialize an array, and the id hashes the kinds in order.
+    #[serde(default, with = "content_kinds")]
+    #[cfg_attr(
+        feature = "json_schema",
+        schemars(with = "Option<Vec<PubkyAppPostKind>>")
+    )]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
-    pub content: Option<PubkyAppPostKind>,
+    pub content: Option<Vec<PubkyAppPostKind>>,
+    /// How `tags` are matched; a missing value means `Any`.
+    ///
+    /// The feed id hashes the serialized config, so setting this field gives
+    /// the feed a new id. It is omitted when `None`, keeping the ids of
+    /// existing feeds unchanged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub tag_match: Option<PubkyAppFeedTagMatch>,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -106,9 +146,135 @@ impl PubkyAppFeedConfig {
 
     /// Getter for `content`.
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter))]
-    pub fn content(&self) -> Option<PubkyAppPostKind> {
+    pub fn content(&self) -> Option<Vec<PubkyAppPostKind>> {
         self.content.clone()
     }
+
+    /// Getter for `tag_match`.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter, js_name = tagMatch))]
+    pub fn tag_match(&self) -> Option<PubkyAppFeedTagMatch> {
+        self.tag_match.clone()
+    }
+}
+
+impl PubkyAppFeedConfig {
+    /// Returns `true` if a post with the given tag labels belongs in this feed.
+    ///
+    /// Checks the `content` kinds and the `tags` (according to `tag_match`).
+    /// `reach` and `sort` depend on the social graph and ranking, and are left
+    /// to the indexer. Post tag labels are sanitized before comparison.
+    pub fn matches(&self, post: &PubkyAppPost, post_tags: &[String]) -> bool {
+        if let Some(kinds) = &self.content {
+            if !kinds.contains(&post.kind) {
+                return false;
+            }
+        }
+
+        match &self.tags {
+            Some(tags) if !tags.is_empty() => {
+                let post_tags: Vec<String> =
+                    post_tags.iter().map(|t| sanitize_tag_label(t)).collect();
+                let has_tag = |tag: &String| post_tags.contains(tag);
+                match self.tag_match.clone().unwrap_or_default() {
+                    PubkyAppFeedTagMatch::Any => tags.iter().any(has_tag),
+                    PubkyAppFeedTagMatch::All => tags.iter().all(has_tag),
+                }
+            }
+            _ => true,
+        }
+    }
+
+    /// Builds a feed config from deep-link query params.
+    ///
+    /// Reads `tags` and `content` as comma-separated lists, and `reach`,
+    /// `layout` and `sort` by their serialized names. Missing params default
+    /// to all reach, columns layout, recent sort, no tags and every content
+    /// kind; other params are ignored. The config is sanitized and validated.
+    pub fn from_query(params: &BTreeMap<String, String>) -> Result<Self, String> {
+        let list = |key: &str| {
+            params.get(key).map(|value| {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        let tags = list("tags").map(|tags| tags.into_iter().map(String::from).collect());
+        let content = list("content")
+            .filter(|kinds| !kinds.is_empty())
+            .map(|kinds| {
+                kinds
+                    .into_iter()
+                    .map(PubkyAppPostKind::from_str)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        let reach = params
+            .get("reach")
+            .map_or(Ok(PubkyAppFeedReach::All), |v| v.parse())?;
+        let layout = params
+            .get("layout")
+            .map_or(Ok(PubkyAppFeedLayout::Columns), |v| v.parse())?;
+        let sort = params
+            .get("sort")
+            .map_or(Ok(PubkyAppFeedSort::Recent), |v| v.parse())?;
+
+        let config = PubkyAppFeedConfig {
+            tags,
+            reach,
+            layout,
+            sort,
+            content,
+            tag_match: None,
+        }
+        .sanitize();
+        config.validate(None)?;
+        Ok(config)
+    }
+
+    /// Returns the JSON Schema of the feed config, for clients generating
+    /// config forms. Enum fields list their allowed values.
+    #[cfg(feature = "json_schema")]
+    pub fn json_schema() -> serde_json::Value {
+        schemars::schema_for!(PubkyAppFeedConfig).to_value()
+    }
+}
+
+/// Serde for `PubkyAppFeedConfig::content`, accepting a single kind string
+/// or an array of kinds.
+mod content_kinds {
+    use crate::PubkyAppPostKind;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(PubkyAppPostKind),
+        Many(Vec<PubkyAppPostKind>),
+    }
+
+    pub fn serialize<S: Serializer>(
+        kinds: &Option<Vec<PubkyAppPostKind>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match kinds.as_deref() {
+            Some([kind]) => kind.serialize(serializer),
+            kinds => kinds.serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<PubkyAppPostKind>>, D::Error> {
+        Ok(
+            Option::<OneOrMany>::deserialize(deserializer)?.map(|kinds| match kinds {
+                OneOrMany::One(kind) => vec![kind],
+                OneOrMany::Many(kinds) => kinds,
+            }),
+        )
+    }
 }
 
 impl Validatable for PubkyAppFeedConfig {
@@ -121,7 +287,21 @@ impl Validatable for PubkyAppFeedConfig {
                 .collect()
         });
 
-        PubkyAppFeedConfig { tags, ..self }
+        // Sanitize content kinds: drop duplicates, keeping the first occurrence
+        let content = self.content.map(|kinds| {
+            kinds.into_iter().fold(Vec::new(), |mut unique, kind| {
+                if !unique.contains(&kind) {
+                    unique.push(kind);
+                }
+                unique
+            })
+        });
+
+        PubkyAppFeedConfig {
+            tags,
+            content,
+            ..self
+        }
     }
 
     fn validate(&self, _id: Option<&str>) -> Result<(), String> {
@@ -141,6 +321,13 @@ impl Validatable for PubkyAppFeedConfig {
             }
         }
 
+        if self.content.as_ref().is_some_and(|kinds| kinds.is_empty()) {
+            return Err(
+                "Validation Error: Feed content kinds cannot be empty, use none for all kinds"
+                    .into(),
+            );
+        }
+
         Ok(())
     }
 }
@@ -152,6 +339,7 @@ impl Json for PubkyAppFeedConfig {}
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct PubkyAppFeed {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
     pub feed: PubkyAppFeedConfig,
@@ -170,13 +358,14 @@ impl PubkyAppFeed {
         content: Option<PubkyAppPostKind>,
         name: String,
     ) -> Self {
-        let created_at = timestamp();
+        let created_at = timestamp_micros();
         let feed = PubkyAppFeedConfig {
             tags,
             reach,
             layout,
             sort,
-            content,
+            content: content.map(|kind| vec![kind]),
+            tag_match: None,
         };
         Self {
             feed,
@@ -185,6 +374,103 @@ impl PubkyAppFeed {
         }
         .sanitize()
     }
+
+    /// Returns advisory warnings for unusual but valid configurations.
+    ///
+    /// Unlike `validate`, lints never reject a feed; clients can surface them
+    /// as hints while the user builds the feed.
+    pub fn lint(&self) -> Vec<String> {
+        let config = &self.feed;
+        let mut warnings = Vec::new();
+
+        if config.tag_match.is_some() && config.tags.as_ref().is_none_or(|t| t.is_empty()) {
+            warnings.push("tag_match has no effect on a feed without tags".to_string());
+        }
+
+        if let Some(kinds) = &config.content {
+            let not_media = kinds
+                .iter()
+                .find(|kind| !matches!(kind, PubkyAppPostKind::Image | PubkyAppPostKind::Video));
+            if let (PubkyAppFeedLayout::Visual, Some(kind)) = (&config.layout, not_media) {
+                warnings.push(format!(
+                    "visual layout is meant for image or video posts, not {kind}"
+                ));
+            }
+
+            if config.reach != PubkyAppFeedReach::All
+                && !kinds.is_empty()
+                && kinds.iter().all(|kind| {
+                    matches!(kind, PubkyAppPostKind::File | PubkyAppPostKind::Collection)
+                })
+            {
+                let kinds: Vec<String> = kinds.iter().map(ToString::to_string).collect();
+                warnings.push(format!(
+                    "{} posts are rare; a {} reach may leave the feed mostly empty",
+                    kinds.join(" and "),
+                    serde_json::to_value(&config.reach)
+                        .ok()
+                        .and_then(|v| v.as_str().map(String::from))
+                        .unwrap_or_default()
+                ));
+            }
+        }
+
+        warnings
+    }
+
+    /// Returns `true` if both feeds have the same `feed` config, ignoring
+    /// `name` and `created_at`. Matches id equivalence: the feed id only
+    /// hashes the config.
+    pub fn same_config(&self, other: &Self) -> bool {
+        self.feed == other.feed
+    }
+
+    /// Built-in feeds every client should offer: "Following", "Friends",
+    /// "Popular" and "Media".
+    ///
+    /// Names and configs are stable, so a preset has the same feed id in
+    /// every app; only `created_at` differs between calls.
+    pub fn presets() -> Vec<PubkyAppFeed> {
+        let preset = |name: &str,
+                      reach: PubkyAppFeedReach,
+                      layout: PubkyAppFeedLayout,
+                      sort: PubkyAppFeedSort,
+                      content: Option<Vec<PubkyAppPostKind>>| {
+            let mut feed = PubkyAppFeed::new(None, reach, layout, sort, None, name.to_string());
+            feed.feed.content = content;
+            feed.sanitize()
+        };
+        vec![
+            preset(
+                "Following",
+                PubkyAppFeedReach::Following,
+                PubkyAppFeedLayout::Columns,
+                PubkyAppFeedSort::Recent,
+                None,
+            ),
+            preset(
+                "Friends",
+                PubkyAppFeedReach::Friends,
+                PubkyAppFeedLayout::Columns,
+                PubkyAppFeedSort::Recent,
+                None,
+            ),
+            preset(
+                "Popular",
+                PubkyAppFeedReach::All,
+                PubkyAppFeedLayout::Columns,
+                PubkyAppFeedSort::Popularity,
+                None,
+            ),
+            preset(
+                "Media",
+                PubkyAppFeedReach::All,
+                PubkyAppFeedLayout::Visual,
+                PubkyAppFeedSort::Recent,
+                Some(vec![PubkyAppPostKind::Image, PubkyAppPostKind::Video]),
+            ),
+        ]
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -232,6 +518,17 @@ impl HasIdPath for PubkyAppFeed {
     }
 }
 
+impl PutPayload for PubkyAppFeed {
+    fn put_path(&self, id: Option<&str>) -> Result<String, String> {
+        let id = id.map_or_else(|| self.create_id(), String::from);
+        Ok(Self::create_path(&id))
+    }
+}
+
+impl ResourceModel for PubkyAppFeed {
+    const KIND: ResourceKind = ResourceKind::Feed;
+}
+
 impl Validatable for PubkyAppFeed {
     fn validate(&self, id: Option<&str>) -> Result<(), String> {
         // Validate the feed ID
@@ -263,6 +560,38 @@ impl Validatable for PubkyAppFeed {
     }
 }
 
+impl PubkyAppFeedReach {
+    /// Every variant, in declaration order.
+    pub fn all() -> &'static [PubkyAppFeedReach] {
+        use PubkyAppFeedReach::*;
+        &[Following, Followers, Friends, All]
+    }
+}
+
+impl PubkyAppFeedLayout {
+    /// Every variant, in declaration order.
+    pub fn all() -> &'static [PubkyAppFeedLayout] {
+        use PubkyAppFeedLayout::*;
+        &[Columns, Wide, Visual, List]
+    }
+}
+
+impl PubkyAppFeedSort {
+    /// Every variant, in declaration order.
+    pub fn all() -> &'static [PubkyAppFeedSort] {
+        use PubkyAppFeedSort::*;
+        &[Recent, Popularity]
+    }
+}
+
+impl PubkyAppFeedTagMatch {
+    /// Every variant, in declaration order.
+    pub fn all() -> &'static [PubkyAppFeedTagMatch] {
+        use PubkyAppFeedTagMatch::*;
+        &[Any, All]
+    }
+}
+
 impl FromStr for PubkyAppFeedReach {
     type Err = String;
 
@@ -324,12 +653,13 @@ mod tests {
             reach: PubkyAppFeedReach::Following,
             layout: PubkyAppFeedLayout::Columns,
             sort: PubkyAppFeedSort::Recent,
-            content: Some(PubkyAppPostKind::Image),
+            content: Some(vec![PubkyAppPostKind::Image]),
+            tag_match: None,
         };
         assert_eq!(feed.feed, feed_config);
         assert_eq!(feed.name, "Rust Bitcoiners");
         // Check that created_at is recent
-        let now = timestamp();
+        let now = timestamp_micros();
         assert!(feed.created_at <= now && feed.created_at >= now - 1_000_000);
     }
 
@@ -645,4 +975,270 @@ mod tests {
         // Invalid case
         assert!("invalid".parse::<PubkyAppFeedSort>().is_err());
     }
+
+    #[cfg(feature = "json_schema")]
+    #[test]
+    fn test_feed_config_json_schema() {
+        let schema = PubkyAppFeedConfig::json_schema();
+        assert_eq!(schema["title"], "PubkyAppFeedConfig");
+        assert!(schema["properties"]["reach"].is_object());
+
+        let reach_values = serde_json::to_value(PubkyAppFeedReach::all()).unwrap();
+        assert_eq!(schema["$defs"]["PubkyAppFeedReach"]["enum"], reach_values);
+    }
+
+    #[test]
+    fn test_feed_enums_all_round_trip() {
+        fn serde_name<T: Serialize>(value: &T) -> String {
+            serde_json::to_value(value)
+                .unwrap()
+                .as_str()
+                .unwrap()
+                .to_string()
+        }
+
+        assert_eq!(PubkyAppFeedReach::all().len(), 4);
+        for reach in PubkyAppFeedReach::all() {
+            assert_eq!(
+                &serde_name(reach).parse::<PubkyAppFeedReach>().unwrap(),
+                reach
+            );
+        }
+        assert_eq!(PubkyAppFeedLayout::all().len(), 4);
+        for layout in PubkyAppFeedLayout::all() {
+            assert_eq!(
+                &serde_name(layout).parse::<PubkyAppFeedLayout>().unwrap(),
+                layout
+            );
+        }
+        assert_eq!(PubkyAppFeedSort::all().len(), 2);
+        for sort in PubkyAppFeedSort::all() {
+            assert_eq!(&serde_name(sort).parse::<PubkyAppFeedSort>().unwrap(), sort);
+        }
+        assert_eq!(PubkyAppFeedTagMatch::all().len(), 2);
+        for tag_match in PubkyAppFeedTagMatch::all() {
+            let json = serde_json::to_value(tag_match).unwrap();
+            assert_eq!(
+                &serde_json::from_value::<PubkyAppFeedTagMatch>(json).unwrap(),
+                tag_match
+            );
+        }
+    }
+
+    #[test]
+    fn test_presets_validate() {
+        let presets = PubkyAppFeed::presets();
+        let names: Vec<&str> = presets.iter().map(|feed| feed.name.as_str()).collect();
+        assert_eq!(names, ["Following", "Friends", "Popular", "Media"]);
+
+        for feed in &presets {
+            let id = feed.create_id();
+            assert!(feed.validate(Some(&id)).is_ok(), "{}", feed.name);
+            assert!(feed.lint().is_empty(), "{}", feed.name);
+        }
+
+        // Stable configs give every client the same preset ids
+        let ids: Vec<String> = presets.iter().map(|feed| feed.create_id()).collect();
+        let again: Vec<String> = PubkyAppFeed::presets()
+            .iter()
+            .map(|feed| feed.create_id())
+            .collect();
+        assert_eq!(ids, again);
+    }
+
+    #[test]
+    fn test_same_config_ignores_created_at() {
+        let feed = PubkyAppFeed::new(
+            Some(vec!["bitcoin".to_string()]),
+            PubkyAppFeedReach::All,
+            PubkyAppFeedLayout::Columns,
+            PubkyAppFeedSort::Recent,
+            None,
+            "Bitcoin".to_string(),
+        );
+        let mut other = feed.clone();
+        other.created_at += 1;
+
+        assert_ne!(feed, other);
+        assert!(feed.same_config(&other));
+        assert_eq!(feed.create_id(), other.create_id());
+
+        other.feed.sort = PubkyAppFeedSort::Popularity;
+        assert!(!feed.same_config(&other));
+    }
+
+    #[test]
+    fn test_matches_tag_mode() {
+        let post = PubkyAppPost::new(
+            "Hello".to_string(),
+            PubkyAppPostKind::Short,
+            None,
+            None,
+            None,
+        );
+        let mut config = PubkyAppFeed::new(
+            Some(vec!["bitcoin".to_string(), "rust".to_string()]),
+            PubkyAppFeedReach::All,
+            PubkyAppFeedLayout::Columns,
+            PubkyAppFeedSort::Recent,
+            None,
+            "Rust Bitcoiners".to_string(),
+        )
+        .feed;
+        let one_tag = vec!["Bitcoin".to_string()];
+        let all_tags = vec!["rust".to_string(), "bitcoin".to_string()];
+
+        // Missing `tag_match` behaves as Any
+        assert!(config.matches(&post, &one_tag));
+        assert!(config.matches(&post, &all_tags));
+        assert!(!config.matches(&post, &["pubky".to_string()]));
+
+        config.tag_match = Some(PubkyAppFeedTagMatch::All);
+        assert!(!config.matches(&post, &one_tag));
+        assert!(config.matches(&post, &all_tags));
+
+        config.content = Some(vec![PubkyAppPostKind::Image]);
+        assert!(!config.matches(&post, &all_tags));
+        config.content = Some(vec![PubkyAppPostKind::Image, post.kind.clone()]);
+        assert!(config.matches(&post, &all_tags));
+    }
+
+    #[test]
+    fn test_from_query() {
+        let query = |pairs: &[(&str, &str)]| -> BTreeMap<String, String> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+
+        let full = PubkyAppFeedConfig::from_query(&query(&[
+            ("tags", "Bitcoin, rust"),
+            ("reach", "friends"),
+            ("layout", "visual"),
+            ("sort", "popularity"),
+            ("content", "image,video"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            full,
+            PubkyAppFeedConfig {
+                tags: Some(vec!["bitcoin".to_string(), "rust".to_string()]),
+                reach: PubkyAppFeedReach::Friends,
+                layout: PubkyAppFeedLayout::Visual,
+                sort: PubkyAppFeedSort::Popularity,
+                content: Some(vec![PubkyAppPostKind::Image, PubkyAppPostKind::Video]),
+                tag_match: None,
+            }
+        );
+
+        let partial =
+            PubkyAppFeedConfig::from_query(&query(&[("sort", "popularity"), ("page", "2")]))
+                .unwrap();
+        assert_eq!(partial.tags, None);
+        assert_eq!(partial.reach, PubkyAppFeedReach::All);
+        assert_eq!(partial.layout, PubkyAppFeedLayout::Columns);
+        assert_eq!(partial.sort, PubkyAppFeedSort::Popularity);
+        assert_eq!(partial.content, None);
+        let empty = PubkyAppFeedConfig::from_query(&query(&[("content", "")])).unwrap();
+        assert_eq!(empty.content, None);
+
+        assert!(PubkyAppFeedConfig::from_query(&query(&[("reach", "everyone")])).is_err());
+        assert!(PubkyAppFeedConfig::from_query(&query(&[("content", "image,gif")])).is_err());
+    }
+
+    #[test]
+    fn test_content_single_and_multiple_kinds() {
+        let config_json = |content: &str| {
+            format!(
+                r#"{{"tags": null, "reach": "all", "layout": "columns", "sort": "recent", "content": {content}}}"#
+            )
+        };
+
+        let single: PubkyAppFeedConfig = serde_json::from_str(&config_json(r#""image""#)).unwrap();
+        assert_eq!(single.content, Some(vec![PubkyAppPostKind::Image]));
+        // A single kind keeps serializing as a string, so its feed id is unchanged
+        assert!(serde_json::to_string(&single)
+            .unwrap()
+            .contains(r#""content":"image""#));
+
+        let multi: PubkyAppFeedConfig =
+            serde_json::from_str(&config_json(r#"["image", "video"]"#)).unwrap();
+        assert_eq!(
+            multi.content,
+            Some(vec![PubkyAppPostKind::Image, PubkyAppPostKind::Video])
+        );
+        assert!(serde_json::to_string(&multi)
+            .unwrap()
+            .contains(r#""content":["image","video"]"#));
+
+        let all: PubkyAppFeedConfig = serde_json::from_str(&config_json("null")).unwrap();
+        assert_eq!(all.content, None);
+        let missing: PubkyAppFeedConfig = serde_json::from_str(
+            r#"{"tags": null, "reach": "all", "layout": "columns", "sort": "recent"}"#,
+        )
+        .unwrap();
+        assert_eq!(missing.content, None);
+
+        let duplicated = PubkyAppFeedConfig {
+            content: Some(vec![PubkyAppPostKind::Video, PubkyAppPostKind::Video]),
+            ..multi.clone()
+        }
+        .sanitize();
+        assert_eq!(duplicated.content, Some(vec![PubkyAppPostKind::Video]));
+
+        let empty = PubkyAppFeedConfig {
+            content: Some(vec![]),
+            ..multi
+        };
+        assert!(empty.validate(None).is_err());
+    }
+
+    #[test]
+    fn test_tag_match_changes_id_only_when_set() {
+        let feed = PubkyAppFeed::new(
+            Some(vec!["bitcoin".to_string()]),
+            PubkyAppFeedReach::All,
+            PubkyAppFeedLayout::Columns,
+            PubkyAppFeedSort::Recent,
+            None,
+            "Bitcoin".to_string(),
+        );
+        assert!(!feed.get_id_data().contains("tag_match"));
+
+        let mut all = feed.clone();
+        all.feed.tag_match = Some(PubkyAppFeedTagMatch::All);
+        assert_ne!(feed.create_id(), all.create_id());
+        assert!(all.get_id_data().contains(r#""tag_match":"all""#));
+    }
+
+    #[test]
+    fn test_lint() {
+        let feed = PubkyAppFeed::new(
+            None,
+            PubkyAppFeedReach::Followers,
+            PubkyAppFeedLayout::Visual,
+            PubkyAppFeedSort::Recent,
+            Some(PubkyAppPostKind::File),
+            "Files from followers".to_string(),
+        );
+        assert!(feed.validate(Some(&feed.create_id())).is_ok());
+        assert_eq!(
+            feed.lint(),
+            vec![
+                "visual layout is meant for image or video posts, not file",
+                "file posts are rare; a followers reach may leave the feed mostly empty",
+            ]
+        );
+
+        let feed = PubkyAppFeed::new(
+            Some(vec!["bitcoin".to_string()]),
+            PubkyAppFeedReach::All,
+            PubkyAppFeedLayout::Visual,
+            PubkyAppFeedSort::Recent,
+            Some(PubkyAppPostKind::Image),
+            "Bitcoin pics".to_string(),
+        );
+        assert!(feed.lint().is_empty());
+    }
 }
@@ -1,8 +1,8 @@
 use crate::{
-    common::timestamp,
+    common::{timestamp_micros, validate_created_at},
     limits::VALIDATION_LIMITS,
-    traits::{HasIdPath, TimestampId, Validatable},
-    APP_PATH, PUBLIC_PATH,
+    traits::{required_id, HasIdPath, PutPayload, ResourceModel, TimestampId, Validatable},
+    ParsedUri, Resource, ResourceKind, APP_PATH, PUBLIC_PATH,
 };
 use mime::Mime;
 use serde::{Deserialize, Serialize};
@@ -42,6 +42,27 @@ pub const VALID_MIME_TYPES: &[&str] = &[
     "video/mpeg",
 ];
 
+/// Where a file's `src` or `thumbnail_src` is hosted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PubkyAppFileSourceKind {
+    /// A `pubky://` URI on a homeserver.
+    Pubky,
+    /// An external `http://` or `https://` URL.
+    Http,
+}
+
+impl PubkyAppFileSourceKind {
+    /// Classifies a source URL by scheme. Returns `None` for anything that
+    /// is not a parseable pubky or http(s) URL.
+    pub fn classify(url: &str) -> Option<Self> {
+        match Url::parse(url).ok()?.scheme() {
+            "pubky" => Some(Self::Pubky),
+            "http" | "https" => Some(Self::Http),
+            _ => None,
+        }
+    }
+}
+
 /// Represents a file uploaded by the user.
 /// URI: /pub/pubky.app/files/:file_id
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
@@ -55,7 +76,13 @@ pub struct PubkyAppFile {
     pub src: String,
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
     pub content_type: String,
-    pub size: usize,
+    /// Size in bytes. Unsigned and 64-bit on every target, so files over 4GB
+    /// are representable on wasm32 too (a `bigint` in JS).
+    pub size: u64,
+    /// Optional URL of a separate thumbnail blob, for media files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub thumbnail_src: Option<String>,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -74,6 +101,10 @@ impl PubkyAppFile {
     pub fn content_type(&self) -> String {
         self.content_type.clone()
     }
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter))]
+    pub fn thumbnail_src(&self) -> Option<String> {
+        self.thumbnail_src.clone()
+    }
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromJson))]
     pub fn from_json(js_value: &JsValue) -> Result<Self, String> {
         Self::import_json(js_value)
@@ -89,19 +120,40 @@ impl Json for PubkyAppFile {}
 
 impl PubkyAppFile {
     /// Creates a new `PubkyAppFile` instance.
-    pub fn new(name: String, src: String, content_type: String, size: usize) -> Self {
-        let created_at = timestamp();
+    pub fn new(name: String, src: String, content_type: String, size: u64) -> Self {
+        let created_at = timestamp_micros();
         Self {
             name,
             created_at,
             src,
             content_type,
             size,
+            thumbnail_src: None,
         }
         .sanitize()
     }
 }
 
+/// Name, size and `src` limits for uploaded files.
+impl PubkyAppFile {
+    pub const MIN_NAME_LENGTH: usize = VALIDATION_LIMITS.file_name_min_length;
+    pub const MAX_NAME_LENGTH: usize = VALIDATION_LIMITS.file_name_max_length;
+    pub const MAX_SRC_LENGTH: usize = VALIDATION_LIMITS.file_src_max_length;
+    pub const MAX_SIZE: u64 = VALIDATION_LIMITS.max_file_size_bytes as u64;
+}
+
+impl PubkyAppFile {
+    /// Classifies where `src` is hosted.
+    pub fn src_kind(&self) -> Option<PubkyAppFileSourceKind> {
+        PubkyAppFileSourceKind::classify(&self.src)
+    }
+
+    /// Classifies where `thumbnail_src` is hosted, if there is a thumbnail.
+    pub fn thumbnail_kind(&self) -> Option<PubkyAppFileSourceKind> {
+        PubkyAppFileSourceKind::classify(self.thumbnail_src.as_deref()?)
+    }
+}
+
 impl TimestampId for PubkyAppFile {}
 
 impl HasIdPath for PubkyAppFile {
@@ -112,16 +164,23 @@ impl HasIdPath for PubkyAppFile {
     }
 }
 
+impl PutPayload for PubkyAppFile {
+    fn put_path(&self, id: Option<&str>) -> Result<String, String> {
+        required_id(id, "file").map(Self::create_path)
+    }
+}
+
+impl ResourceModel for PubkyAppFile {
+    const KIND: ResourceKind = ResourceKind::File;
+}
+
 impl Validatable for PubkyAppFile {
     fn sanitize(self) -> Self {
         let name = self.name.trim().to_string();
 
-        let sanitized_src = self
-            .src
-            .trim()
-            .chars()
-            .take(VALIDATION_LIMITS.file_src_max_length)
-            .collect::<String>();
+        // Not truncated: a cut URL would point elsewhere, so an over-length
+        // src is left for `validate` to reject
+        let sanitized_src = self.src.trim().to_string();
 
         let src = match Url::parse(&sanitized_src) {
             Ok(_) => Some(sanitized_src),
@@ -130,12 +189,20 @@ impl Validatable for PubkyAppFile {
 
         let content_type = self.content_type.trim().to_string();
 
+        // Sanitize thumbnail: trim, dropping it if empty. Invalid URLs are
+        // kept for `validate` to reject.
+        let thumbnail_src = self
+            .thumbnail_src
+            .map(|thumbnail| thumbnail.trim().to_string())
+            .filter(|thumbnail| !thumbnail.is_empty());
+
         Self {
             name,
             created_at: self.created_at,
             src: src.unwrap_or("".to_string()),
             content_type,
             size: self.size,
+            thumbnail_src,
         }
     }
 
@@ -145,11 +212,13 @@ impl Validatable for PubkyAppFile {
             self.validate_id(id)?;
         }
 
+        validate_created_at(self.created_at)?;
+
         // Validate size
         if self.size == 0 {
             return Err("Validation Error: File size cannot be zero".to_string());
         }
-        if self.size > VALIDATION_LIMITS.max_file_size_bytes {
+        if self.size > Self::MAX_SIZE {
             return Err("Validation Error: File size exceeds maximum limit of 100MB".to_string());
         }
 
@@ -162,16 +231,40 @@ impl Validatable for PubkyAppFile {
             return Err("Validation Error: Invalid name length".into());
         }
 
-        // Validate src
-        if self.src.chars().count() == 0 {
-            return Err("Validation Error: Invalid src".into());
-        }
-        if self.src.chars().count() > VALIDATION_LIMITS.file_src_max_length {
-            return Err("Validation Error: src exceeds maximum length".into());
+        // Validate src. `sanitize` empties an unparseable src, so an empty
+        // src is reported as missing rather than as too short.
+        if self.src.trim().is_empty() {
+            return Err("Validation Error: Invalid src: missing or not a valid URL".into());
         }
-        // Validate URL format
-        Url::parse(&self.src)
+        let src_url = Url::parse(&self.src)
             .map_err(|_| "Validation Error: Invalid src URI format".to_string())?;
+        // Count the normalized form, which is what ends up being resolved
+        let src_length = src_url.as_str().chars().count();
+        if src_length > Self::MAX_SRC_LENGTH {
+            return Err(format!(
+                "Validation Error: src exceeds maximum length ({} > {} characters)",
+                src_length,
+                Self::MAX_SRC_LENGTH
+            ));
+        }
+
+        // Validate thumbnail: a pubky or http(s) URL, bounded like `src`
+        if let Some(thumbnail) = &self.thumbnail_src {
+            let thumbnail_url = Url::parse(thumbnail)
+                .ok()
+                .filter(|url| matches!(url.scheme(), "pubky" | "http" | "https"))
+                .ok_or_else(|| {
+                    "Validation Error: thumbnail_src must be a pubky or http(s) URL".to_string()
+                })?;
+            let thumbnail_length = thumbnail_url.as_str().chars().count();
+            if thumbnail_length > Self::MAX_SRC_LENGTH {
+                return Err(format!(
+                    "Validation Error: thumbnail_src exceeds maximum length ({} > {} characters)",
+                    thumbnail_length,
+                    Self::MAX_SRC_LENGTH
+                ));
+            }
+        }
 
         // validate content type
         match Mime::from_str(&self.content_type) {
@@ -186,6 +279,20 @@ impl Validatable for PubkyAppFile {
         }
         Ok(())
     }
+
+    fn validate_strict(&self, id: Option<&str>) -> Result<(), String> {
+        self.validate(id)?;
+
+        // Files stored on a homeserver must reference their blob;
+        // external `https://` sources are only accepted by `validate`.
+        match ParsedUri::try_from(self.src.as_str()) {
+            Ok(ParsedUri {
+                resource: Resource::Blob(_),
+                ..
+            }) => Ok(()),
+            _ => Err("Validation Error: src must be a pubky blob URI".into()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -206,7 +313,7 @@ mod tests {
         assert_eq!(file.content_type, "image/png");
         assert_eq!(file.size, 1024);
         // Check that created_at is recent
-        let now = timestamp();
+        let now = timestamp_micros();
         assert!(file.created_at <= now && file.created_at >= now - 1_000_000); // within 1 second
     }
 
@@ -275,7 +382,7 @@ mod tests {
                     "example.png".to_string(),
                     blob_uri_builder("user_id".into(), "id".into()),
                     "image/png".to_string(),
-                    VALIDATION_LIMITS.max_file_size_bytes + 1,
+                    PubkyAppFile::MAX_SIZE + 1,
                 ),
                 "exceeds maximum limit",
             ),
@@ -308,10 +415,11 @@ mod tests {
         // Create file directly without sanitization to test validation logic
         let file = PubkyAppFile {
             name: "example.png".to_string(),
-            created_at: timestamp(),
+            created_at: timestamp_micros(),
             src: "not_a_url".to_string(), // Invalid URL - sanitization would filter this
             content_type: "image/png".to_string(),
             size: 1024,
+            thumbnail_src: None,
         };
         let id = file.create_id();
         let result = file.validate(Some(&id));
@@ -319,12 +427,119 @@ mod tests {
         assert!(result.unwrap_err().contains("Invalid src"));
     }
 
+    #[test]
+    fn test_validate_src_empty_vs_too_long() {
+        let file_with_src = |src: String| {
+            PubkyAppFile::new(
+                "example.png".to_string(),
+                src,
+                "image/png".to_string(),
+                1024,
+            )
+            .sanitize()
+        };
+
+        // Sanitize empties an unparseable src
+        let empty = file_with_src("not a url".to_string());
+        assert_eq!(empty.src, "");
+        let err = empty.validate(Some(&empty.create_id())).unwrap_err();
+        assert!(err.contains("missing or not a valid URL"), "{err}");
+
+        // An over-length src is kept whole and rejected on its length
+        let base = "https://example.com/";
+        let long_src = format!(
+            "{}{}",
+            base,
+            "a".repeat(PubkyAppFile::MAX_SRC_LENGTH - base.len() + 1)
+        );
+        let too_long = file_with_src(long_src.clone());
+        assert_eq!(too_long.src, long_src);
+        let err = too_long.validate(Some(&too_long.create_id())).unwrap_err();
+        assert!(err.contains("src exceeds maximum length"), "{err}");
+
+        let at_limit = file_with_src(long_src[..long_src.len() - 1].to_string());
+        assert!(at_limit.validate(Some(&at_limit.create_id())).is_ok());
+    }
+
+    #[test]
+    fn test_thumbnail_src() {
+        let file = PubkyAppFile::new(
+            "clip.mp4".to_string(),
+            blob_uri_builder("user_id".into(), "id".into()),
+            "video/mp4".to_string(),
+            1024,
+        );
+        // Without a thumbnail
+        assert_eq!(file.thumbnail_src, None);
+        assert_eq!(file.thumbnail_kind(), None);
+        assert_eq!(file.src_kind(), Some(PubkyAppFileSourceKind::Pubky));
+        assert!(file.validate(None).is_ok());
+        let json = serde_json::to_value(&file).unwrap();
+        assert!(json.get("thumbnail_src").is_none());
+
+        // With a thumbnail
+        let with_thumbnail = PubkyAppFile {
+            thumbnail_src: Some(" https://example.com/thumb.png ".to_string()),
+            ..file.clone()
+        }
+        .sanitize();
+        assert_eq!(
+            with_thumbnail.thumbnail_src.as_deref(),
+            Some("https://example.com/thumb.png")
+        );
+        assert_eq!(
+            with_thumbnail.thumbnail_kind(),
+            Some(PubkyAppFileSourceKind::Http)
+        );
+        assert!(with_thumbnail.validate(None).is_ok());
+
+        // An empty thumbnail is dropped
+        let empty = PubkyAppFile {
+            thumbnail_src: Some("  ".to_string()),
+            ..file.clone()
+        }
+        .sanitize();
+        assert_eq!(empty.thumbnail_src, None);
+
+        // Invalid thumbnails are rejected
+        for invalid in ["not a url", "ftp://example.com/thumb.png"] {
+            let invalid_file = PubkyAppFile {
+                thumbnail_src: Some(invalid.to_string()),
+                ..file.clone()
+            }
+            .sanitize();
+            let err = invalid_file.validate(None).unwrap_err();
+            assert!(err.contains("thumbnail_src"), "{err}");
+        }
+
+        // Length is counted on the normalized URL, like `src`: a space fits
+        // the raw limit but is percent-encoded to three characters
+        let base = "https://example.com/";
+        let fill = "a".repeat(PubkyAppFile::MAX_SRC_LENGTH - base.len() - 2);
+        let too_long = PubkyAppFile {
+            thumbnail_src: Some(format!("{base}a {fill}")),
+            ..file
+        };
+        assert_eq!(
+            too_long.thumbnail_src.as_ref().unwrap().chars().count(),
+            PubkyAppFile::MAX_SRC_LENGTH
+        );
+        assert_eq!(
+            too_long.validate(None).unwrap_err(),
+            format!(
+                "Validation Error: thumbnail_src exceeds maximum length ({} > {} characters)",
+                PubkyAppFile::MAX_SRC_LENGTH + 2,
+                PubkyAppFile::MAX_SRC_LENGTH
+            )
+        );
+    }
+
     #[test]
     fn test_try_from_valid() {
         let file_json = r#"
         {
             "name": "example.png",
-            "created_at": 1627849723,
+            "created_at": 1733011200000000,
             "src": "pubky://user_id/pub/pubky.app/blobs/id",
             "content_type": "image/png",
             "size": 1024
@@ -347,4 +562,93 @@ mod tests {
         assert_eq!(file_parsed.content_type, "image/png");
         assert_eq!(file_parsed.size, 1024);
     }
+
+    #[test]
+    fn test_large_size_round_trips_as_u64() {
+        // Larger than u32::MAX, so it would not fit a 32-bit usize.
+        let large_size: u64 = 5_000_000_000;
+        let file_json = format!(
+            r#"{{
+                "name": "archive.zip",
+                "created_at": 1733011200000000,
+                "src": "pubky://user_id/pub/pubky.app/blobs/id",
+                "content_type": "application/zip",
+                "size": {large_size}
+            }}"#
+        );
+        let file: PubkyAppFile = serde_json::from_str(&file_json).unwrap();
+        assert_eq!(file.size, large_size);
+        let reserialized = serde_json::to_value(&file).unwrap();
+        assert_eq!(reserialized["size"], large_size);
+
+        // Parses fine but is still above the size limit
+        let err = file.validate(None).unwrap_err();
+        assert!(err.contains("exceeds maximum limit"));
+
+        // A negative size is no longer representable
+        let negative = file_json.replace(&large_size.to_string(), "-1");
+        assert!(serde_json::from_str::<PubkyAppFile>(&negative).is_err());
+    }
+
+    #[test]
+    fn test_validate_strict_src() {
+        let pubky_blob_src = blob_uri_builder(
+            "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".into(),
+            "CDW1T5RM4PHP64QT0P6RE4PNT0".into(),
+        );
+        let file = PubkyAppFile::new(
+            "example.png".to_string(),
+            pubky_blob_src,
+            "image/png".to_string(),
+            1024,
+        );
+        let id = file.create_id();
+        assert!(file.validate(Some(&id)).is_ok());
+        assert!(file.validate_strict(Some(&id)).is_ok());
+
+        let https_file = PubkyAppFile::new(
+            "example.png".to_string(),
+            "https://example.com/example.png".to_string(),
+            "image/png".to_string(),
+            1024,
+        );
+        let id = https_file.create_id();
+        assert!(https_file.validate(Some(&id)).is_ok());
+        let result = https_file.validate_strict(Some(&id));
+        assert!(result.unwrap_err().contains("pubky blob URI"));
+
+        // A pubky URI pointing at something other than a blob is also rejected
+        let post_file = PubkyAppFile::new(
+            "example.png".to_string(),
+            "pubky://operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo/pub/pubky.app/posts/0032SSN7Q4EVG".to_string(),
+            "image/png".to_string(),
+            1024,
+        );
+        let id = post_file.create_id();
+        assert!(post_file.validate_strict(Some(&id)).is_err());
+    }
+
+    #[test]
+    fn test_validate_created_at() {
+        let mut file = PubkyAppFile::new(
+            "example.png".to_string(),
+            blob_uri_builder("user_id".into(), "id".into()),
+            "image/png".to_string(),
+            1024,
+        );
+        let id = file.create_id();
+        assert!(file.validate(Some(&id)).is_ok());
+
+        file.created_at = -1;
+        assert!(file
+            .validate(Some(&id))
+            .unwrap_err()
+            .contains("created_at must be after"));
+
+        file.created_at = timestamp_micros() + 3 * 60 * 60 * 1_000_000;
+        assert!(file
+            .validate(Some(&id))
+            .unwrap_err()
+            .contains("created_at is too far in the future"));
+    }
 }
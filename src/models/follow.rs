@@ -1,7 +1,7 @@
 use crate::{
-    common::timestamp,
-    traits::{HasIdPath, Validatable},
-    PubkyId, APP_PATH, PUBLIC_PATH,
+    common::timestamp_micros,
+    traits::{required_id, HasIdPath, PutPayload, ResourceModel, Validatable},
+    PubkyId, ResourceKind, APP_PATH, PUBLIC_PATH,
 };
 use serde::{Deserialize, Serialize};
 
@@ -23,6 +23,9 @@ use utoipa::ToSchema;
 ///
 /// `/pub/pubky.app/follows/pxnu33x7jtpx9ar1ytsi4yxbp6a5o36gwhffs8zoxmbuptici1jy`
 ///
+/// The derived `Default` has `created_at = 0` and is only a placeholder: it
+/// falls outside the window accepted by `validate_created_at`. Use `new` or
+/// `new_at` to build a follow to publish.
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -36,9 +39,23 @@ pub struct PubkyAppFollow {
 impl PubkyAppFollow {
     /// Creates a new `PubkyAppFollow` instance.
     pub fn new() -> Self {
-        let created_at = timestamp();
+        Self::new_at(timestamp_micros())
+    }
+
+    /// Creates a `PubkyAppFollow` with the given `created_at`, in microseconds.
+    pub fn new_at(created_at: i64) -> Self {
         Self { created_at }
     }
+
+    /// Runs `validate` for the `followee` id, additionally rejecting a follow
+    /// of the `author` themselves.
+    pub fn validate_with_author(&self, followee: &str, author: &PubkyId) -> Result<(), String> {
+        self.validate(Some(followee))?;
+        if PubkyId::try_from(followee)? == *author {
+            return Err("Validation Error: A user cannot follow themselves".into());
+        }
+        Ok(())
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -77,16 +94,26 @@ impl HasIdPath for PubkyAppFollow {
     }
 }
 
+impl PutPayload for PubkyAppFollow {
+    fn put_path(&self, id: Option<&str>) -> Result<String, String> {
+        required_id(id, "follow").map(Self::create_path)
+    }
+}
+
+impl ResourceModel for PubkyAppFollow {
+    const KIND: ResourceKind = ResourceKind::Follow;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::traits::Validatable;
+    use crate::{traits::Validatable, validate_created_at};
 
     #[test]
     fn test_new() {
         let follow = PubkyAppFollow::new();
         // Check that created_at is recent
-        let now = timestamp();
+        let now = timestamp_micros();
         // within 1 second
         assert!(follow.created_at <= now && follow.created_at >= now - 1_000_000);
     }
@@ -111,6 +138,27 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_validate_with_author() {
+        let author =
+            PubkyId::try_from("operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo").unwrap();
+        let follow = PubkyAppFollow::new();
+
+        assert!(follow
+            .validate_with_author(
+                "pxnu33x7jtpx9ar1ytsi4yxbp6a5o36gwhffs8zoxmbuptici1jy",
+                &author
+            )
+            .is_ok());
+        let err = follow
+            .validate_with_author(author.as_ref(), &author)
+            .unwrap_err();
+        assert!(err.contains("follow themselves"));
+        assert!(follow
+            .validate_with_author("not_a_valid_pubky_id", &author)
+            .is_err());
+    }
+
     #[test]
     fn test_try_from_valid() {
         let follow_json = r#"
@@ -128,4 +176,19 @@ mod tests {
 
         assert_eq!(follow_parsed.created_at, 1627849723);
     }
+
+    #[test]
+    fn test_new_at() {
+        let follow = PubkyAppFollow::new_at(1_733_011_200_000_000);
+        assert_eq!(follow.created_at, 1_733_011_200_000_000);
+        assert!(validate_created_at(follow.created_at).is_ok());
+    }
+
+    #[test]
+    fn test_default_is_zero_timestamp_placeholder() {
+        let follow = PubkyAppFollow::default();
+        assert_eq!(follow.created_at, 0);
+        assert!(validate_created_at(follow.created_at).is_err());
+        assert!(validate_created_at(PubkyAppFollow::new().created_at).is_ok());
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{
-    common::timestamp,
-    traits::{HasPath, Validatable},
-    APP_PATH, PUBLIC_PATH,
+    common::timestamp_millis,
+    traits::{HasPath, PutPayload, ResourceModel, Validatable},
+    ResourceKind, APP_PATH, PUBLIC_PATH,
 };
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +15,9 @@ use utoipa::ToSchema;
 
 /// Represents the last read timestamp for notifications.
 /// URI: /pub/pubky.app/last_read
+///
+/// Unlike other models, which store microseconds, `timestamp` is stored in
+/// **milliseconds** since the UNIX epoch.
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -25,9 +28,21 @@ pub struct PubkyAppLastRead {
 impl PubkyAppLastRead {
     /// Creates a new `PubkyAppLastRead` instance.
     pub fn new() -> Self {
-        let timestamp = timestamp() / 1_000; // Convert to milliseconds
+        let timestamp = timestamp_millis();
         Self { timestamp }
     }
+
+    /// Returns `true` if the last read time is strictly after `other_ms`
+    /// (milliseconds since the UNIX epoch), i.e. a notification created at
+    /// `other_ms` has already been seen.
+    pub fn is_after(&self, other_ms: i64) -> bool {
+        self.timestamp > other_ms
+    }
+
+    /// Updates the last read time to now, in milliseconds.
+    pub fn touch(&mut self) {
+        self.timestamp = timestamp_millis();
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -65,6 +80,16 @@ impl HasPath for PubkyAppLastRead {
     }
 }
 
+impl PutPayload for PubkyAppLastRead {
+    fn put_path(&self, _id: Option<&str>) -> Result<String, String> {
+        Ok(Self::create_path())
+    }
+}
+
+impl ResourceModel for PubkyAppLastRead {
+    const KIND: ResourceKind = ResourceKind::LastRead;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,7 +98,7 @@ mod tests {
     #[test]
     fn test_new() {
         let last_read = PubkyAppLastRead::new();
-        let now = timestamp() / 1_000;
+        let now = timestamp_millis();
         // within 1 second
         assert!(last_read.timestamp <= now && last_read.timestamp >= now - 1_000);
     }
@@ -110,4 +135,25 @@ mod tests {
         let last_read = <PubkyAppLastRead as Validatable>::try_from(blob, "").unwrap();
         assert_eq!(last_read.timestamp, 1700000000);
     }
+
+    #[test]
+    fn test_is_after() {
+        let last_read = PubkyAppLastRead {
+            timestamp: 1_733_011_200_000,
+        };
+        assert!(last_read.is_after(1_733_011_199_999));
+        assert!(!last_read.is_after(1_733_011_200_000));
+        assert!(!last_read.is_after(1_733_011_200_001));
+    }
+
+    #[test]
+    fn test_touch() {
+        let mut last_read = PubkyAppLastRead {
+            timestamp: 1_733_011_200_000,
+        };
+        last_read.touch();
+        let now = timestamp_millis();
+        assert!(last_read.timestamp > 1_733_011_200_000);
+        assert!(last_read.timestamp <= now && last_read.timestamp >= now - 1_000);
+    }
 }
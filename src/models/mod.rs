@@ -1,4 +1,8 @@
-use crate::{traits::Validatable, ParsedUri, Resource};
+use crate::{
+    traits::{HashId, Validatable},
+    ParsedUri, Resource,
+};
+use std::fmt;
 
 pub mod blob;
 pub mod bookmark;
@@ -39,11 +43,59 @@ impl PubkyAppObject {
         Self::from_resource(&parsed_uri.resource, blob)
     }
 
+    /// Like `from_uri`, for a blob that is already JSON text.
+    pub fn from_uri_str<S: AsRef<str>>(uri: S, json: &str) -> Result<Self, String> {
+        Self::from_uri(uri, json.as_bytes())
+    }
+
+    /// Serializes the wrapped object back to JSON.
+    ///
+    /// With the `unknown-fields` feature, fields that `User` and `Post` did not
+    /// recognize on import are written back, so an import/export round trip
+    /// does not drop data written by a newer schema.
+    pub fn re_export_json(&self) -> Result<serde_json::Value, String> {
+        let value = match self {
+            PubkyAppObject::User(user) => serde_json::to_value(user),
+            PubkyAppObject::Post(post) => serde_json::to_value(post),
+            PubkyAppObject::Follow(follow) => serde_json::to_value(follow),
+            PubkyAppObject::Mute(mute) => serde_json::to_value(mute),
+            PubkyAppObject::Bookmark(bookmark) => serde_json::to_value(bookmark),
+            PubkyAppObject::Tag(tag) => serde_json::to_value(tag),
+            PubkyAppObject::File(file) => serde_json::to_value(file),
+            PubkyAppObject::Blob(blob) => serde_json::to_value(blob),
+            PubkyAppObject::Feed(feed) => serde_json::to_value(feed),
+            PubkyAppObject::LastRead(last_read) => serde_json::to_value(last_read),
+        };
+        value.map_err(|e| format!("JSON serialization error: {}", e))
+    }
+
+    /// Returns the id of content-addressed objects (`Tag`, `Bookmark`, `Feed`
+    /// and `Blob`), computed via `create_id`.
+    ///
+    /// Returns `None` for `User` and `LastRead`, which have no id, and for
+    /// variants whose id is not derivable from the object: `Post` and `File`
+    /// use creation-time ids, and `Follow`/`Mute` are keyed by the target
+    /// user id. Use the `ParsedUri` the object was imported from for those.
+    pub fn id(&self) -> Option<String> {
+        match self {
+            PubkyAppObject::Tag(tag) => Some(tag.create_id()),
+            PubkyAppObject::Bookmark(bookmark) => Some(bookmark.create_id()),
+            PubkyAppObject::Feed(feed) => Some(feed.create_id()),
+            PubkyAppObject::Blob(blob) => Some(blob.create_id()),
+            PubkyAppObject::User(_)
+            | PubkyAppObject::LastRead(_)
+            | PubkyAppObject::Post(_)
+            | PubkyAppObject::File(_)
+            | PubkyAppObject::Follow(_)
+            | PubkyAppObject::Mute(_) => None,
+        }
+    }
+
     /// Given a Resource and a blob (raw data from the homeserver),
     /// this function returns the fully formed PubkyAppObject.
     pub fn from_resource(resource: &Resource, blob: &[u8]) -> Result<Self, String> {
         match resource {
-            Resource::User => {
+            Resource::User(_) => {
                 // For a user, no ID is needed (or you may use an empty string)
                 let user = <PubkyAppUser as Validatable>::try_from(blob, "")?;
                 Ok(PubkyAppObject::User(user))
@@ -85,7 +137,127 @@ impl PubkyAppObject {
                 let last_read = <PubkyAppLastRead as Validatable>::try_from(blob, "")?;
                 Ok(PubkyAppObject::LastRead(last_read))
             }
-            Resource::Unknown => Err(format!("Unrecognized resource {:?}", resource)),
+            Resource::Root | Resource::Unknown(_) => {
+                Err(format!("Unrecognized resource: {}", resource))
+            }
+        }
+    }
+
+    /// Like `from_uri`, but returns the object even if it fails validation.
+    ///
+    /// Validation errors are collected into the returned `Vec` instead of
+    /// failing the import. Only URI parsing, unknown resources and JSON
+    /// deserialization errors are hard failures.
+    pub fn from_uri_lenient<S: AsRef<str>>(
+        uri: S,
+        blob: &[u8],
+    ) -> Result<(Self, Vec<String>), String> {
+        let parsed_uri = ParsedUri::try_from(uri.as_ref())?;
+        Self::from_resource_lenient(&parsed_uri.resource, blob)
+    }
+
+    /// Like `from_resource`, but returns the object even if it fails validation.
+    pub fn from_resource_lenient(
+        resource: &Resource,
+        blob: &[u8],
+    ) -> Result<(Self, Vec<String>), String> {
+        match resource {
+            Resource::User(_) => {
+                let (user, notes) = <PubkyAppUser as Validatable>::try_from_lenient(blob, "")?;
+                Ok((PubkyAppObject::User(user), notes))
+            }
+            Resource::Post(post_id) => {
+                let (post, notes) = <PubkyAppPost as Validatable>::try_from_lenient(blob, post_id)?;
+                Ok((PubkyAppObject::Post(post), notes))
+            }
+            Resource::Follow(follow_id) => {
+                let (follow, notes) =
+                    <PubkyAppFollow as Validatable>::try_from_lenient(blob, follow_id)?;
+                Ok((PubkyAppObject::Follow(follow), notes))
+            }
+            Resource::Mute(muted_id) => {
+                let (mute, notes) =
+                    <PubkyAppMute as Validatable>::try_from_lenient(blob, muted_id)?;
+                Ok((PubkyAppObject::Mute(mute), notes))
+            }
+            Resource::Bookmark(bookmark_id) => {
+                let (bookmark, notes) =
+                    <PubkyAppBookmark as Validatable>::try_from_lenient(blob, bookmark_id)?;
+                Ok((PubkyAppObject::Bookmark(bookmark), notes))
+            }
+            Resource::Tag(tag_id) => {
+                let (tag, notes) = <PubkyAppTag as Validatable>::try_from_lenient(blob, tag_id)?;
+                Ok((PubkyAppObject::Tag(tag), notes))
+            }
+            Resource::File(file_id) => {
+                let (file, notes) = <PubkyAppFile as Validatable>::try_from_lenient(blob, file_id)?;
+                Ok((PubkyAppObject::File(file), notes))
+            }
+            Resource::Blob(blob_id) => {
+                let (blob_obj, notes) =
+                    <PubkyAppBlob as Validatable>::try_from_lenient(blob, blob_id)?;
+                Ok((PubkyAppObject::Blob(blob_obj), notes))
+            }
+            Resource::Feed(feed_id) => {
+                let (feed, notes) = <PubkyAppFeed as Validatable>::try_from_lenient(blob, feed_id)?;
+                Ok((PubkyAppObject::Feed(feed), notes))
+            }
+            Resource::LastRead => {
+                let (last_read, notes) =
+                    <PubkyAppLastRead as Validatable>::try_from_lenient(blob, "")?;
+                Ok((PubkyAppObject::LastRead(last_read), notes))
+            }
+            Resource::Root | Resource::Unknown(_) => {
+                Err(format!("Unrecognized resource: {}", resource))
+            }
+        }
+    }
+}
+
+/// Concise one-line summary for logging. Use `{:?}` for the full object.
+impl fmt::Display for PubkyAppObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PubkyAppObject::User(user) => write!(f, "User(name={})", user.name),
+            PubkyAppObject::Post(post) => write!(
+                f,
+                "Post(kind={}, {} chars)",
+                post.kind,
+                post.content.chars().count()
+            ),
+            PubkyAppObject::Follow(follow) => {
+                write!(f, "Follow(created_at={})", follow.created_at)
+            }
+            PubkyAppObject::Mute(mute) => write!(f, "Mute(created_at={})", mute.created_at),
+            PubkyAppObject::Bookmark(bookmark) => {
+                write!(
+                    f,
+                    "Bookmark(id={}, uri={})",
+                    bookmark.create_id(),
+                    bookmark.uri
+                )
+            }
+            PubkyAppObject::Tag(tag) => write!(
+                f,
+                "Tag(id={}, label={}, uri={})",
+                tag.create_id(),
+                tag.label,
+                tag.uri
+            ),
+            PubkyAppObject::File(file) => write!(
+                f,
+                "File(name={}, {}, {} bytes)",
+                file.name, file.content_type, file.size
+            ),
+            PubkyAppObject::Blob(blob) => {
+                write!(f, "Blob(id={}, {} bytes)", blob.create_id(), blob.0.len())
+            }
+            PubkyAppObject::Feed(feed) => {
+                write!(f, "Feed(id={}, name={})", feed.create_id(), feed.name)
+            }
+            PubkyAppObject::LastRead(last_read) => {
+                write!(f, "LastRead(timestamp={})", last_read.timestamp)
+            }
         }
     }
 }
@@ -247,7 +419,7 @@ mod tests {
             r#"{{
             "uri": "{post_uri}",
             "label": "cool",
-            "created_at": 1627849726
+            "created_at": 1733011200000000
         }}"#
         );
         let result = PubkyAppObject::from_uri(uri, tag_json.as_bytes());
@@ -264,6 +436,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_import_from_uri_str() {
+        let uri = tag_uri_builder(
+            "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".into(),
+            "86805FC1CSFZD4W6HZ09S24QWG".into(),
+        );
+        let tag_json = format!(
+            r#"{{
+            "uri": "{}",
+            "label": "cool",
+            "created_at": 1733011200000000
+        }}"#,
+            post_uri_builder(
+                "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".into(),
+                "0032SSN7Q4EVG".into(),
+            )
+        );
+
+        let from_str = PubkyAppObject::from_uri_str(&uri, &tag_json).unwrap();
+        let from_bytes = PubkyAppObject::from_uri(&uri, tag_json.as_bytes()).unwrap();
+        assert_eq!(from_str.to_string(), from_bytes.to_string());
+        assert!(matches!(from_str, PubkyAppObject::Tag(_)));
+
+        assert!(PubkyAppObject::from_uri_str(&uri, "not json").is_err());
+    }
+
+    #[test]
+    fn test_re_export_json() {
+        let uri = post_uri_builder(
+            "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".into(),
+            "0032SSN7Q4EVG".into(),
+        );
+        let post_json = r#"{"content": "Hello", "kind": "short", "reactions": {"likes": 3}}"#;
+        let object = PubkyAppObject::from_uri_str(&uri, post_json).unwrap();
+        let exported = object.re_export_json().unwrap();
+        assert_eq!(exported["content"], "Hello");
+        assert_eq!(exported["kind"], "short");
+
+        #[cfg(feature = "unknown-fields")]
+        assert_eq!(exported["reactions"], serde_json::json!({"likes": 3}));
+        #[cfg(not(feature = "unknown-fields"))]
+        assert!(exported.get("reactions").is_none());
+    }
+
     #[test]
     fn test_import_file() {
         let uri = file_uri_builder(
@@ -272,7 +488,7 @@ mod tests {
         );
         let file_json = r#"{
             "name": "example.png",
-            "created_at": 1627849727,
+            "created_at": 1733011200000000,
             "src": "https://example.com/example.png",
             "content_type": "image/png",
             "size": 1024
@@ -391,4 +607,193 @@ mod tests {
             err
         );
     }
+
+    #[test]
+    fn test_import_covers_every_resource_kind() {
+        use crate::ResourceKind;
+
+        let user_id =
+            crate::PubkyId::try_from("operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo")
+                .unwrap();
+        // Exhaustive on purpose: a new `ResourceKind` fails to compile here
+        // until it is given a resource, and must then be added to `kinds`.
+        let resource_for = |kind: ResourceKind| match kind {
+            ResourceKind::User => Resource::User(None),
+            ResourceKind::Post => Resource::Post("0032SSN7Q4EVG".into()),
+            ResourceKind::Follow => Resource::Follow(user_id.clone()),
+            ResourceKind::Mute => Resource::Mute(user_id.clone()),
+            ResourceKind::Bookmark => Resource::Bookmark("AF7KQ6NEV5XV1EG5DVJ2E74JJ4".into()),
+            ResourceKind::Tag => Resource::Tag("FPB0AM9S93Q3M1GFY1KV09GMQM".into()),
+            ResourceKind::File => Resource::File("0032SSN7Q4EVG".into()),
+            ResourceKind::Blob => Resource::Blob("AF7KQ6NEV5XV1EG5DVJ2E74JJ4".into()),
+            ResourceKind::Feed => Resource::Feed("AF7KQ6NEV5XV1EG5DVJ2E74JJ4".into()),
+            ResourceKind::LastRead => Resource::LastRead,
+            ResourceKind::Root => Resource::Root,
+            ResourceKind::Unknown => Resource::Unknown(None),
+        };
+        let kinds = [
+            ResourceKind::User,
+            ResourceKind::Post,
+            ResourceKind::Follow,
+            ResourceKind::Mute,
+            ResourceKind::Bookmark,
+            ResourceKind::Tag,
+            ResourceKind::File,
+            ResourceKind::Blob,
+            ResourceKind::Feed,
+            ResourceKind::LastRead,
+            ResourceKind::Root,
+            ResourceKind::Unknown,
+        ];
+
+        for kind in kinds {
+            let resource = resource_for(kind);
+            assert_eq!(resource.kind(), kind);
+
+            // Whatever the blob, a wired kind is handed to its model and only
+            // `Root`/`Unknown` are rejected by the dispatch itself
+            let unrecognized = |result: Result<(), String>| {
+                result.is_err_and(|err| err.contains("Unrecognized resource"))
+            };
+            let strict = PubkyAppObject::from_resource(&resource, b"not json").map(|_| ());
+            let lenient = PubkyAppObject::from_resource_lenient(&resource, b"not json").map(|_| ());
+            let expected = matches!(kind, ResourceKind::Root | ResourceKind::Unknown);
+            assert_eq!(unrecognized(strict), expected, "{kind:?}");
+            assert_eq!(unrecognized(lenient), expected, "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn test_import_lenient_returns_invalid_object() {
+        let uri = user_uri_builder("operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".into());
+        let long_name = "a".repeat(100);
+        let user_json = format!(r#"{{"name": "{long_name}"}}"#);
+
+        // Strict import rejects the over-length name
+        assert!(PubkyAppObject::from_uri(&uri, user_json.as_bytes()).is_err());
+
+        let (object, notes) = PubkyAppObject::from_uri_lenient(&uri, user_json.as_bytes())
+            .expect("Lenient import should only fail on parse errors");
+        match object {
+            PubkyAppObject::User(user) => assert_eq!(user.name, long_name),
+            other => panic!("Expected a User object, got {:?}", other),
+        }
+        assert_eq!(notes.len(), 1);
+        assert!(notes[0].contains("Invalid name length"), "{}", notes[0]);
+    }
+
+    #[test]
+    fn test_import_lenient_valid_object_has_no_notes() {
+        let uri = user_uri_builder("operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".into());
+        let (_, notes) = PubkyAppObject::from_uri_lenient(uri, br#"{"name": "Alice"}"#).unwrap();
+        assert!(notes.is_empty());
+    }
+
+    #[test]
+    fn test_import_lenient_json_error_is_hard_failure() {
+        let uri = user_uri_builder("operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".into());
+        assert!(PubkyAppObject::from_uri_lenient(uri, b"not json").is_err());
+    }
+
+    #[test]
+    fn test_display_summary() {
+        let post = PubkyAppObject::Post(PubkyAppPost::new(
+            "Hello".to_string(),
+            post::PubkyAppPostKind::Short,
+            None,
+            None,
+            None,
+        ));
+        assert_eq!(post.to_string(), "Post(kind=short, 5 chars)");
+
+        let user = PubkyAppObject::User(PubkyAppUser::new(
+            "Alice".to_string(),
+            None,
+            None,
+            None,
+            None,
+        ));
+        assert_eq!(user.to_string(), "User(name=Alice)");
+
+        let follow = PubkyAppObject::Follow(PubkyAppFollow {
+            created_at: 1733011200000000,
+        });
+        assert_eq!(follow.to_string(), "Follow(created_at=1733011200000000)");
+    }
+
+    #[test]
+    fn test_object_id() {
+        let user_id = "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo";
+        let tag = PubkyAppTag::new(
+            post_uri_builder(user_id.into(), "0032SSN7Q4EVG".into()),
+            "rust".to_string(),
+        );
+        let tag_id = tag.create_id();
+        let uri = tag_uri_builder(user_id.into(), tag_id.clone());
+        let json = serde_json::to_vec(&tag).unwrap();
+
+        let object = PubkyAppObject::from_uri(uri, &json).unwrap();
+        assert_eq!(object.id(), Some(tag_id));
+
+        let user = PubkyAppObject::User(PubkyAppUser::default());
+        assert_eq!(user.id(), None);
+    }
+
+    #[test]
+    fn test_crate_root_exports_models_types() {
+        use std::any::TypeId;
+
+        // The crate root must only expose the `models::` definitions
+        assert_eq!(
+            TypeId::of::<crate::PubkyAppUser>(),
+            TypeId::of::<user::PubkyAppUser>()
+        );
+        assert_eq!(
+            TypeId::of::<crate::PubkyAppPost>(),
+            TypeId::of::<post::PubkyAppPost>()
+        );
+        assert_eq!(
+            TypeId::of::<crate::PubkyAppFeed>(),
+            TypeId::of::<feed::PubkyAppFeed>()
+        );
+        assert_eq!(
+            TypeId::of::<crate::PubkyAppFile>(),
+            TypeId::of::<file::PubkyAppFile>()
+        );
+        assert_eq!(
+            TypeId::of::<crate::PubkyAppBookmark>(),
+            TypeId::of::<bookmark::PubkyAppBookmark>()
+        );
+        assert_eq!(
+            TypeId::of::<crate::PubkyAppMute>(),
+            TypeId::of::<mute::PubkyAppMute>()
+        );
+        assert_eq!(
+            TypeId::of::<crate::PubkyAppTag>(),
+            TypeId::of::<tag::PubkyAppTag>()
+        );
+    }
+
+    #[test]
+    fn test_resource_model_kind() {
+        use crate::{traits::ResourceModel, ResourceKind};
+
+        fn import_as<T: ResourceModel + Validatable>(uri: &str, blob: &[u8]) -> Result<T, String> {
+            let parsed = ParsedUri::try_from(uri)?;
+            if parsed.resource.kind() != T::KIND {
+                return Err(format!("Expected a {:?} resource", T::KIND));
+            }
+            let id = parsed.resource.id().unwrap_or_default();
+            T::try_from(blob, &id)
+        }
+
+        let user_id = "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo";
+        let profile = user_uri_builder(user_id.into());
+        let user: PubkyAppUser = import_as(&profile, br#"{"name": "Alice"}"#).unwrap();
+        assert_eq!(user.name, "Alice");
+        assert_eq!(PubkyAppUser::KIND, ResourceKind::User);
+
+        let err = import_as::<PubkyAppPost>(&profile, br#"{"name": "Alice"}"#).unwrap_err();
+        assert_eq!(err, "Expected a Post resource");
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{
-    common::timestamp,
-    traits::{HasIdPath, Validatable},
-    PubkyId, APP_PATH, PUBLIC_PATH,
+    common::timestamp_micros,
+    traits::{required_id, HasIdPath, PutPayload, ResourceModel, Validatable},
+    PubkyId, ResourceKind, APP_PATH, PUBLIC_PATH,
 };
 use serde::{Deserialize, Serialize};
 
@@ -30,9 +30,19 @@ pub struct PubkyAppMute {
 impl PubkyAppMute {
     /// Creates a new `PubkyAppMute` instance.
     pub fn new() -> Self {
-        let created_at = timestamp();
+        let created_at = timestamp_micros();
         Self { created_at }
     }
+
+    /// Runs `validate` for the `mutee` id, additionally rejecting a mute of
+    /// the `author` themselves.
+    pub fn validate_with_author(&self, mutee: &str, author: &PubkyId) -> Result<(), String> {
+        self.validate(Some(mutee))?;
+        if PubkyId::try_from(mutee)? == *author {
+            return Err("Validation Error: A user cannot mute themselves".into());
+        }
+        Ok(())
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -71,17 +81,27 @@ impl HasIdPath for PubkyAppMute {
     }
 }
 
+impl PutPayload for PubkyAppMute {
+    fn put_path(&self, id: Option<&str>) -> Result<String, String> {
+        required_id(id, "mute").map(Self::create_path)
+    }
+}
+
+impl ResourceModel for PubkyAppMute {
+    const KIND: ResourceKind = ResourceKind::Mute;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::common::timestamp;
+    use crate::common::timestamp_micros;
     use crate::traits::Validatable;
 
     #[test]
     fn test_new() {
         let mute = PubkyAppMute::new();
         // Check that created_at is recent
-        let now = timestamp();
+        let now = timestamp_micros();
         assert!(mute.created_at <= now && mute.created_at >= now - 1_000_000);
         // within 1 second
     }
@@ -110,6 +130,27 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_validate_with_author() {
+        let author =
+            PubkyId::try_from("operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo").unwrap();
+        let mute = PubkyAppMute::new();
+
+        assert!(mute
+            .validate_with_author(
+                "pxnu33x7jtpx9ar1ytsi4yxbp6a5o36gwhffs8zoxmbuptici1jy",
+                &author
+            )
+            .is_ok());
+        let err = mute
+            .validate_with_author(author.as_ref(), &author)
+            .unwrap_err();
+        assert!(err.contains("mute themselves"));
+        assert!(mute
+            .validate_with_author("not_a_valid_pubky_id", &author)
+            .is_err());
+    }
+
     #[test]
     fn test_try_from_valid() {
         let mute_json = r#"
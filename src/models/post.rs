@@ -1,12 +1,17 @@
 use crate::{
-    common::{sanitize_url, validate_crockford_id},
+    common::{
+        blake3_crockford_id, is_disallowed_control_char, is_disallowed_invisible_char,
+        normalize_line_endings, sanitize_url, strip_disallowed_control_chars,
+        strip_disallowed_invisible_chars, validate_crockford_id, validate_schema_version,
+    },
     limits::VALIDATION_LIMITS,
-    traits::{HasIdPath, TimestampId, Validatable},
+    post_uri_builder,
+    traits::{required_id, HasIdPath, PutPayload, ResourceModel, TimestampId, Validatable},
     types::PubkyId,
-    APP_PATH, PUBLIC_PATH,
+    ParsedUri, Resource, ResourceKind, APP_PATH, CURRENT_SCHEMA_VERSION, PUBLIC_PATH,
 };
 use serde::{Deserialize, Serialize};
-use std::{fmt, str::FromStr};
+use std::{collections::HashSet, fmt, str::FromStr};
 use url::Url;
 
 // Reserved keyword used by the system to mark deleted posts with relationships
@@ -28,6 +33,8 @@ use utoipa::ToSchema;
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[serde(rename_all = "lowercase")]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub enum PubkyAppPostKind {
     #[default]
     Short,
@@ -80,6 +87,19 @@ impl PubkyAppPostKind {
     pub fn is_known(&self) -> bool {
         !matches!(self, PubkyAppPostKind::Unknown)
     }
+
+    /// Every spec-recognized variant, in declaration order. Excludes `Unknown`.
+    pub fn all() -> &'static [PubkyAppPostKind] {
+        use PubkyAppPostKind::*;
+        &[Short, Long, Image, Video, Link, File, Collection]
+    }
+
+    /// Lenient counterpart of `FromStr`: returns `Unknown` instead of an error
+    /// for a kind this version of the spec doesn't recognize, matching how
+    /// serde deserializes it.
+    pub fn from_str_or_unknown(s: &str) -> PubkyAppPostKind {
+        s.parse().unwrap_or(PubkyAppPostKind::Unknown)
+    }
 }
 
 /// Represents embedded content within a post
@@ -121,6 +141,17 @@ impl PubkyAppPostEmbed {
     }
 }
 
+impl PubkyAppPostEmbed {
+    /// Builds a `Short` embed quoting the post `post_id` by `author`, with
+    /// the canonical `pubky://<author>/pub/pubky.app/posts/<post_id>` URI.
+    pub fn from_post(author: &PubkyId, post_id: &str) -> Self {
+        PubkyAppPostEmbed {
+            kind: PubkyAppPostKind::Short,
+            uri: post_uri_builder(author.to_string(), post_id.to_string()),
+        }
+    }
+}
+
 /// Typed JSON envelope stored in `PubkyAppPost::content` when `kind == Collection`.
 ///
 /// A collection post curates an ordered list of URIs (via `items`)
@@ -170,6 +201,10 @@ pub struct PubkyAppCollectionContent {
 /// Example URI:
 ///
 /// `/pub/pubky.app/posts/00321FCW75ZFY`
+///
+/// Note: the derived `Default` (empty content, `Short` kind) is a placeholder
+/// and does **not** pass validation, since a post needs content, an embed or
+/// attachments. Use `PubkyAppPost::new` to build a post meant to be stored.
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Serialize, Deserialize, Default, Clone, Debug)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -184,6 +219,22 @@ pub struct PubkyAppPost {
     pub embed: Option<PubkyAppPostEmbed>,
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
     pub attachments: Option<Vec<String>>,
+    /// Pubky IDs of users explicitly mentioned by the post, for notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub mentions: Option<Vec<String>>,
+    /// Version of the post schema that produced this object.
+    /// A missing value means version 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub schema_version: Option<u16>,
+    /// Fields not defined by this version of the spec, kept so objects written
+    /// by a newer schema survive an import/export round trip.
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    #[cfg_attr(feature = "openapi", schema(ignore))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -208,6 +259,13 @@ impl PubkyAppPost {
         }
     }
 
+    /// Typed counterpart of `kind`, so JS can compare against the exported
+    /// `PubkyAppPostKind` enum instead of a display string.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter, js_name = kindEnum))]
+    pub fn kind_enum(&self) -> PubkyAppPostKind {
+        self.kind.clone()
+    }
+
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter))]
     pub fn parent(&self) -> Option<String> {
         self.parent.clone()
@@ -223,6 +281,11 @@ impl PubkyAppPost {
         self.attachments.clone()
     }
 
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter))]
+    pub fn mentions(&self) -> Option<Vec<String>> {
+        self.mentions.clone()
+    }
+
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromJson))]
     pub fn from_json(js_value: &JsValue) -> Result<Self, String> {
         Self::import_json(js_value)
@@ -254,11 +317,151 @@ impl PubkyAppPost {
             parent,
             embed,
             attachments,
+            mentions: None,
+            schema_version: None,
+            #[cfg(feature = "unknown-fields")]
+            extra: Default::default(),
         };
         post.sanitize()
     }
 }
 
+impl PubkyAppPost {
+    /// Creates a sanitized `Short` post with no content, parent, embed or
+    /// attachments.
+    ///
+    /// This is an explicit starting point for building a post field by field.
+    /// Like `Default`, it does not validate until content, an embed or
+    /// attachments are set.
+    pub fn empty_short() -> Self {
+        Self::new(String::new(), PubkyAppPostKind::Short, None, None, None)
+    }
+
+    /// Builds the URI of the post `id` written by `author`, of the form
+    /// `pubky://<author>/pub/pubky.app/posts/<id>`.
+    pub fn self_uri(author: &PubkyId, id: &str) -> String {
+        post_uri_builder(author.to_string(), id.to_string())
+    }
+
+    /// Returns a stable fingerprint of the post, independent of its id.
+    ///
+    /// Hashes the sanitized content, kind, embed and attachments with Blake3
+    /// and encodes it like content-addressed ids, so two posts with identical
+    /// content share a fingerprint. Useful to detect re-posted spam.
+    pub fn content_fingerprint(&self) -> String {
+        let post = self.clone().sanitize();
+        let data =
+            serde_json::to_string(&(&post.content, &post.kind, &post.embed, &post.attachments))
+                .unwrap_or_default();
+        blake3_crockford_id(data.as_bytes())
+    }
+
+    /// Returns `true` if the post is the `[DELETED]` tombstone a homeserver
+    /// keeps in place of a deleted post that still has relationships.
+    pub fn is_deleted(&self) -> bool {
+        self.content == RESERVED_CONTENT_DELETED
+    }
+
+    /// Returns whether the post replies to a post by `author`, i.e. the author
+    /// continuing their own thread.
+    ///
+    /// Returns `None` when the post is not a reply, or when the parent is not
+    /// a parseable pubky URI and its author cannot be determined.
+    pub fn is_self_reply(&self, author: &PubkyId) -> Option<bool> {
+        let parent = ParsedUri::try_from(self.parent.as_deref()?).ok()?;
+        Some(&parent.user_id == author)
+    }
+
+    /// Returns `true` when the trimmed content is made only of emoji (and
+    /// whitespace), e.g. a reaction-style `"🔥🔥"`.
+    ///
+    /// Detection is range-based: pictographic code points plus the joiners,
+    /// variation selectors, skin-tone modifiers and keycaps that build emoji
+    /// sequences. Bare ASCII digits, `#` and `*` don't count as emoji.
+    pub fn is_emoji_only(&self) -> bool {
+        let mut has_pictograph = false;
+        for c in self.content.trim().chars() {
+            if is_pictographic(c) {
+                has_pictograph = true;
+            } else if !(c.is_whitespace() || is_emoji_component(c)) {
+                return false;
+            }
+        }
+        has_pictograph
+    }
+
+    /// Parses the embed uri, if any, into a `ParsedUri`.
+    ///
+    /// Returns `None` when the post has no embed, and `Some(Err(..))` when the
+    /// embed does not point at a pubky.app resource. Clients resolving quote
+    /// chains can compare the target against the post's own uri to detect a
+    /// self-embed and cap the quote depth.
+    pub fn embed_target(&self) -> Option<Result<ParsedUri, String>> {
+        self.embed
+            .as_ref()
+            .map(|embed| ParsedUri::try_from(embed.uri.as_str()))
+    }
+
+    /// Returns the attachments that parse as pubky.app resource URIs, in
+    /// order, skipping http(s) and otherwise unparseable entries. Lets clients
+    /// prefetch homeserver files before rendering the post.
+    pub fn pubky_attachments(&self) -> Vec<ParsedUri> {
+        self.attachments
+            .iter()
+            .flatten()
+            .filter_map(|uri| ParsedUri::try_from(uri.as_str()).ok())
+            .collect()
+    }
+
+    /// Infers a display kind for each attachment, in order, without fetching
+    /// it. http(s) URLs are classified by the extension of their path as
+    /// `Image` or `Video`; everything else, including `pubky://` file URIs
+    /// whose type is only known after fetching, is `File`.
+    pub fn attachment_kinds(&self) -> Vec<PubkyAppPostKind> {
+        self.attachments
+            .iter()
+            .flatten()
+            .map(|uri| attachment_kind(uri))
+            .collect()
+    }
+}
+
+/// Display kind of a single attachment URI, see `attachment_kinds`.
+fn attachment_kind(uri: &str) -> PubkyAppPostKind {
+    let Ok(url) = Url::parse(uri) else {
+        return PubkyAppPostKind::File;
+    };
+    if !matches!(url.scheme(), "http" | "https") {
+        return PubkyAppPostKind::File;
+    }
+    let extension = url
+        .path()
+        .rsplit('/')
+        .next()
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("png" | "jpg" | "jpeg" | "gif" | "webp" | "avif" | "svg" | "bmp") => {
+            PubkyAppPostKind::Image
+        }
+        Some("mp4" | "webm" | "mov" | "m4v" | "mkv" | "ogv") => PubkyAppPostKind::Video,
+        _ => PubkyAppPostKind::File,
+    }
+}
+
+/// Content, attachment and mention limits, per post kind where they differ.
+impl PubkyAppPost {
+    pub const MAX_SHORT_CONTENT_LENGTH: usize = VALIDATION_LIMITS.post_short_content_max_length;
+    pub const MAX_LONG_CONTENT_LENGTH: usize = VALIDATION_LIMITS.post_long_content_max_length;
+    pub const MAX_MEDIA_CAPTION_LENGTH: usize = VALIDATION_LIMITS.post_media_caption_max_length;
+    pub const MAX_CONTENT_BYTES: usize = VALIDATION_LIMITS.post_content_max_bytes;
+    pub const MAX_ATTACHMENTS: usize = VALIDATION_LIMITS.post_attachments_max_count;
+    pub const MAX_ATTACHMENT_URL_LENGTH: usize = VALIDATION_LIMITS.post_attachment_url_max_length;
+    pub const MAX_HTTP_ATTACHMENT_URL_LENGTH: usize =
+        VALIDATION_LIMITS.post_attachment_http_url_max_length;
+    pub const MAX_MENTIONS: usize = VALIDATION_LIMITS.post_mentions_max_count;
+}
+
 impl TimestampId for PubkyAppPost {}
 
 impl HasIdPath for PubkyAppPost {
@@ -269,10 +472,40 @@ impl HasIdPath for PubkyAppPost {
     }
 }
 
-impl Validatable for PubkyAppPost {
-    fn sanitize(self) -> Self {
-        // Sanitize content: trim whitespace only
-        let content = self.content.trim().to_string();
+impl PutPayload for PubkyAppPost {
+    fn put_path(&self, id: Option<&str>) -> Result<String, String> {
+        required_id(id, "post").map(Self::create_path)
+    }
+}
+
+impl ResourceModel for PubkyAppPost {
+    const KIND: ResourceKind = ResourceKind::Post;
+}
+
+impl PubkyAppPost {
+    /// Like `sanitize`, but `Long` content only loses surrounding ASCII
+    /// whitespace. Unicode spacing that can be intentional formatting, such as
+    /// the ideographic space (U+3000) in CJK text, is preserved. Other kinds
+    /// are sanitized as usual.
+    pub fn sanitize_ascii_trim(self) -> Self {
+        self.sanitize_trimming(true)
+    }
+
+    /// Sanitizes the post; `Long` content is trimmed of ASCII whitespace only
+    /// when `ascii_trim` is set.
+    fn sanitize_trimming(self, ascii_trim: bool) -> Self {
+        // Sanitize content: canonicalize line endings to `\n`, strip bidi-control,
+        // zero-width and control characters (except newlines and tabs), then trim
+        // outer whitespace. Long (markdown) posts only lose surrounding blank
+        // lines, so the indentation of a leading code block is preserved.
+        let content = normalize_line_endings(&self.content);
+        let content = strip_disallowed_invisible_chars(&content);
+        let content = strip_disallowed_control_chars(&content);
+        let content = match self.kind {
+            PubkyAppPostKind::Long => trim_blank_lines(&content, ascii_trim),
+            _ => content.trim(),
+        }
+        .to_string();
 
         // Sanitize parent URI if present
         let parent = self.parent.map(|uri_str| sanitize_url(&uri_str));
@@ -283,7 +516,9 @@ impl Validatable for PubkyAppPost {
             uri: sanitize_url(&e.uri),
         });
 
-        // Sanitize attachments
+        // Sanitize attachments: each URL is sanitized in place, so the order is
+        // always preserved. Invalid URLs are kept at their index (trimmed) for
+        // `validate` to reject, never dropped or reordered.
         let attachments = self.attachments.map(|attachments_vec| {
             attachments_vec
                 .into_iter()
@@ -291,14 +526,34 @@ impl Validatable for PubkyAppPost {
                 .collect()
         });
 
+        // Sanitize mentions: drop entries that are not valid pubky ids
+        let mentions = self.mentions.map(|mentions| {
+            mentions
+                .iter()
+                .filter_map(|id| PubkyId::try_from(id.trim()).ok())
+                .map(|id| id.to_string())
+                .collect()
+        });
+
         PubkyAppPost {
             content,
             kind: self.kind,
             parent,
             embed,
             attachments,
+            mentions,
+            // Stamp objects that predate the field with the current version
+            schema_version: self.schema_version.or(Some(CURRENT_SCHEMA_VERSION)),
+            #[cfg(feature = "unknown-fields")]
+            extra: self.extra,
         }
     }
+}
+
+impl Validatable for PubkyAppPost {
+    fn sanitize(self) -> Self {
+        self.sanitize_trimming(false)
+    }
 
     fn validate(&self, id: Option<&str>) -> Result<(), String> {
         // Validate the post ID
@@ -306,6 +561,8 @@ impl Validatable for PubkyAppPost {
             self.validate_id(id)?;
         }
 
+        validate_schema_version(self.schema_version)?;
+
         // Validate that post has meaningful content (at least one of: content, embed, or attachments)
         if self.content.trim().is_empty() && self.embed.is_none() && self.attachments.is_none() {
             return Err(
@@ -322,6 +579,38 @@ impl Validatable for PubkyAppPost {
             );
         }
 
+        if self.content.chars().any(is_disallowed_invisible_char) {
+            return Err(
+                "Validation Error: Content contains disallowed bidi-control or zero-width characters"
+                    .into(),
+            );
+        }
+
+        if self.content.chars().any(is_disallowed_control_char) {
+            return Err("Validation Error: Content contains disallowed control characters".into());
+        }
+
+        if self.content.len() > VALIDATION_LIMITS.post_content_max_bytes {
+            return Err(format!(
+                "Validation Error: Post content exceeds maximum size (max: {} bytes)",
+                VALIDATION_LIMITS.post_content_max_bytes
+            ));
+        }
+
+        // Validate mentions
+        if let Some(mentions) = &self.mentions {
+            if mentions.len() > VALIDATION_LIMITS.post_mentions_max_count {
+                return Err(format!(
+                    "Validation Error: Too many mentions (max: {})",
+                    VALIDATION_LIMITS.post_mentions_max_count
+                ));
+            }
+            for mention in mentions {
+                PubkyId::try_from(mention)
+                    .map_err(|_| format!("Validation Error: Invalid mention: {}", mention))?;
+            }
+        }
+
         // Reject posts whose kind couldn't be matched against any known variant.
         // `Unknown` is a serde catch-all for forwards-compat: older binaries can
         // deserialize events from newer clients without panicking, but such posts
@@ -432,7 +721,7 @@ impl Validatable for PubkyAppPost {
             | PubkyAppPostKind::Video
             | PubkyAppPostKind::Link
             | PubkyAppPostKind::File => (
-                VALIDATION_LIMITS.post_short_content_max_length,
+                VALIDATION_LIMITS.post_media_caption_max_length,
                 "Image/Video/Link/File",
             ),
             PubkyAppPostKind::Collection | PubkyAppPostKind::Unknown => {
@@ -455,13 +744,40 @@ impl Validatable for PubkyAppPost {
                     parent_uri
                 )
             })?;
+            // The parent must name its author and point at a post
+            let parsed = ParsedUri::try_from(parent_uri.as_str()).map_err(|e| {
+                format!("Validation Error: Invalid parent URI {}: {}", parent_uri, e)
+            })?;
+            if !matches!(parsed.resource, Resource::Post(_)) {
+                return Err(format!(
+                    "Validation Error: Parent URI must reference a post: {}",
+                    parent_uri
+                ));
+            }
         }
 
         // Validate embed URI format if present
         if let Some(ref embed) = self.embed {
-            Url::parse(&embed.uri).map_err(|_| {
+            let parsed_url = Url::parse(&embed.uri).map_err(|_| {
                 format!("Validation Error: Invalid embed URI format: {}", embed.uri)
             })?;
+
+            // Ensure the URI uses an allowed protocol, as for attachments
+            if !VALIDATION_LIMITS
+                .post_allowed_attachment_protocols
+                .contains(&parsed_url.scheme())
+            {
+                let allowed_protocols = VALIDATION_LIMITS
+                    .post_allowed_attachment_protocols
+                    .iter()
+                    .map(|p| format!("{}://", p))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(format!(
+                    "Validation Error: embed URI must use one of the allowed protocols: {}",
+                    allowed_protocols
+                ));
+            }
         }
 
         // Validate attachments
@@ -473,6 +789,7 @@ impl Validatable for PubkyAppPost {
                 ));
             }
 
+            let mut seen = HashSet::new();
             for (index, url) in attachments.iter().enumerate() {
                 if url.trim().is_empty() {
                     return Err(format!(
@@ -480,12 +797,6 @@ impl Validatable for PubkyAppPost {
                         index
                     ));
                 }
-                if url.chars().count() > VALIDATION_LIMITS.post_attachment_url_max_length {
-                    return Err(format!(
-                        "Validation Error: Attachment URL at index {} exceeds maximum length (max: {} characters)",
-                        index, VALIDATION_LIMITS.post_attachment_url_max_length
-                    ));
-                }
                 // Validate URL format and ensure it uses an allowed protocol
                 let parsed_url = Url::parse(url).map_err(|_| {
                     format!(
@@ -494,6 +805,20 @@ impl Validatable for PubkyAppPost {
                     )
                 })?;
 
+                // http(s) URLs may be long; pubky URLs are short references
+                let max_length = match parsed_url.scheme() {
+                    "http" | "https" => VALIDATION_LIMITS.post_attachment_http_url_max_length,
+                    _ => VALIDATION_LIMITS.post_attachment_url_max_length,
+                };
+                if url.chars().count() > max_length {
+                    return Err(format!(
+                        "Validation Error: Attachment URL at index {} exceeds maximum length for {}:// (max: {} characters)",
+                        index,
+                        parsed_url.scheme(),
+                        max_length
+                    ));
+                }
+
                 // Ensure the URL uses an allowed protocol
                 if !VALIDATION_LIMITS
                     .post_allowed_attachment_protocols
@@ -510,11 +835,143 @@ impl Validatable for PubkyAppPost {
                         index, allowed_protocols
                     ));
                 }
+
+                // Compare the parsed (normalized) form, so case or encoding
+                // variants of the same URL count as duplicates
+                if !seen.insert(parsed_url.to_string()) {
+                    return Err(format!(
+                        "Validation Error: Duplicate attachment URL at index {}",
+                        index
+                    ));
+                }
             }
         }
 
         Ok(())
     }
+
+    fn validate_strict(&self, id: Option<&str>) -> Result<(), String> {
+        if let Some(ref embed) = self.embed {
+            validate_embed_strict(embed)?;
+
+            // Replying to and quoting the same post is usually a client bug
+            if let Some(ref parent) = self.parent {
+                if sanitize_url(parent) == sanitize_url(&embed.uri) {
+                    return Err(
+                        "Validation Error: parent and embed must not reference the same URI".into(),
+                    );
+                }
+            }
+        }
+
+        self.validate(id)
+    }
+}
+
+/// Trims leading blank lines and trailing whitespace, leaving the first
+/// non-blank line (including its indentation) and all interior content as is.
+/// With `ascii_only`, only ASCII whitespace counts as blank.
+fn trim_blank_lines(content: &str, ascii_only: bool) -> &str {
+    let is_blank = |c: char| {
+        if ascii_only {
+            c.is_ascii_whitespace()
+        } else {
+            c.is_whitespace()
+        }
+    };
+    let content = content.trim_end_matches(is_blank);
+    let mut start = 0;
+    for line in content.split_inclusive('\n') {
+        if !line.chars().all(is_blank) {
+            break;
+        }
+        start += line.len();
+    }
+    &content[start..]
+}
+
+/// Approximates the Unicode `Extended_Pictographic` property.
+fn is_pictographic(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00A9}'
+            | '\u{00AE}'
+            | '\u{203C}'
+            | '\u{2049}'
+            | '\u{2122}'
+            | '\u{2139}'
+            | '\u{2194}'..='\u{2199}'
+            | '\u{21A9}'..='\u{21AA}'
+            | '\u{231A}'..='\u{231B}'
+            | '\u{2328}'
+            | '\u{23CF}'
+            | '\u{23E9}'..='\u{23F3}'
+            | '\u{23F8}'..='\u{23FA}'
+            | '\u{24C2}'
+            | '\u{25AA}'..='\u{25AB}'
+            | '\u{25B6}'
+            | '\u{25C0}'
+            | '\u{25FB}'..='\u{25FE}'
+            | '\u{2600}'..='\u{27BF}'
+            | '\u{2934}'..='\u{2935}'
+            | '\u{2B05}'..='\u{2B07}'
+            | '\u{2B1B}'..='\u{2B1C}'
+            | '\u{2B50}'
+            | '\u{2B55}'
+            | '\u{3030}'
+            | '\u{303D}'
+            | '\u{3297}'
+            | '\u{3299}'
+            | '\u{1F000}'..='\u{1FAFF}'
+    )
+}
+
+/// Characters that only modify or join emoji: ZWJ, variation selectors,
+/// the combining keycap, skin-tone modifiers and tag characters.
+fn is_emoji_component(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200D}' | '\u{FE0E}' | '\u{FE0F}' | '\u{20E3}' | '\u{1F3FB}'..='\u{1F3FF}' | '\u{E0020}'..='\u{E007F}'
+    )
+}
+
+/// Shape checks on an embed that cannot be done without fetching the target:
+/// the uri must be non-empty and parseable, and when it points at a pubky.app
+/// resource the declared `kind` must be coherent with it. Posts accept any
+/// known kind; files only accept the media kinds (`image`, `video`, `file`);
+/// any other pubky.app resource cannot be embedded.
+fn validate_embed_strict(embed: &PubkyAppPostEmbed) -> Result<(), String> {
+    if embed.uri.trim().is_empty() {
+        return Err("Validation Error: embed uri cannot be empty".into());
+    }
+    let url = Url::parse(&embed.uri)
+        .map_err(|_| format!("Validation Error: Invalid embed URI format: {}", embed.uri))?;
+    if url.scheme() != "pubky" {
+        return Ok(());
+    }
+
+    let coherent = match ParsedUri::try_from(embed.uri.as_str()) {
+        Ok(ParsedUri {
+            resource: Resource::Post(_),
+            ..
+        }) => embed.kind.is_known(),
+        Ok(ParsedUri {
+            resource: Resource::File(_),
+            ..
+        }) => matches!(
+            embed.kind,
+            PubkyAppPostKind::Image | PubkyAppPostKind::Video | PubkyAppPostKind::File
+        ),
+        _ => false,
+    };
+    if !coherent {
+        return Err(format!(
+            "Validation Error: embed kind {} does not match embedded resource: {}",
+            embed.kind, embed.uri
+        ));
+    }
+
+    Ok(())
 }
 
 /// Strict canonical post-URI check for Collection items. Accepts only the
@@ -722,6 +1179,37 @@ mod tests {
         assert!(result.unwrap_err().contains("Invalid parent URI format"));
     }
 
+    #[test]
+    fn test_validate_parent_must_be_post_uri() {
+        let with_parent = |parent: &str| {
+            PubkyAppPost::new(
+                "Reply".to_string(),
+                PubkyAppPostKind::Short,
+                Some(parent.to_string()),
+                None,
+                None,
+            )
+            .sanitize()
+        };
+
+        let valid = with_parent(
+            "pubky://operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo/pub/pubky.app/posts/0034A0X7NJ52G",
+        );
+        assert!(valid.validate(Some(&valid.create_id())).is_ok());
+
+        let hostless = with_parent("pubky:///pub/pubky.app/posts/0034A0X7NJ52G");
+        let err = hostless.validate(Some(&hostless.create_id())).unwrap_err();
+        assert!(err.contains("Invalid parent URI"), "{err}");
+
+        let not_a_post = with_parent(
+            "pubky://operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo/pub/pubky.app/profile.json",
+        );
+        let err = not_a_post
+            .validate(Some(&not_a_post.create_id()))
+            .unwrap_err();
+        assert!(err.contains("must reference a post"), "{err}");
+    }
+
     #[test]
     fn test_validate_invalid_embed_uri() {
         let post = PubkyAppPost::new(
@@ -742,6 +1230,34 @@ mod tests {
         assert!(result.unwrap_err().contains("Invalid embed URI format"));
     }
 
+    #[test]
+    fn test_validate_embed_uri_scheme() {
+        let with_embed = |uri: &str| {
+            PubkyAppPost::new(
+                "Valid content".to_string(),
+                PubkyAppPostKind::Short,
+                None,
+                Some(PubkyAppPostEmbed {
+                    kind: PubkyAppPostKind::Short,
+                    uri: uri.to_string(),
+                }),
+                None,
+            )
+        };
+
+        let script = with_embed("javascript:alert(1)");
+        let err = script.validate(Some(&script.create_id())).unwrap_err();
+        assert!(
+            err.contains("embed URI must use one of the allowed protocols"),
+            "{err}"
+        );
+
+        let pubky = with_embed(
+            "pubky://operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo/pub/pubky.app/posts/0032SSN7Q4EVG",
+        );
+        assert!(pubky.validate(Some(&pubky.create_id())).is_ok());
+    }
+
     #[test]
     fn test_validate_invalid_attachment_uri() {
         let post = PubkyAppPost::new(
@@ -918,6 +1434,10 @@ mod tests {
                 parent: None,
                 embed: None,
                 attachments: Some(vec![invalid_url.to_string()]),
+                mentions: None,
+                schema_version: None,
+                #[cfg(feature = "unknown-fields")]
+                extra: Default::default(),
             };
 
             let id = post.create_id();
@@ -936,6 +1456,10 @@ mod tests {
             parent: None,
             embed: None,
             attachments: Some(vec!["not a valid url".to_string()]),
+            mentions: None,
+            schema_version: None,
+            #[cfg(feature = "unknown-fields")]
+            extra: Default::default(),
         };
 
         let id = post.create_id();
@@ -979,6 +1503,46 @@ mod tests {
         assert!(result.unwrap_err().contains("exceeds maximum length"));
     }
 
+    #[test]
+    fn test_validate_attachment_length_per_scheme() {
+        let with_attachment = |url: String| {
+            PubkyAppPost::new(
+                "Valid content".to_string(),
+                PubkyAppPostKind::Link,
+                None,
+                None,
+                Some(vec![url]),
+            )
+        };
+
+        // A long https URL, over the pubky limit, is accepted
+        let long_https = format!(
+            "https://example.com/article?utm_source={}",
+            "a".repeat(PubkyAppPost::MAX_ATTACHMENT_URL_LENGTH)
+        );
+        assert!(long_https.len() < PubkyAppPost::MAX_HTTP_ATTACHMENT_URL_LENGTH);
+        let post = with_attachment(long_https);
+        assert!(post.validate(Some(&post.create_id())).is_ok());
+
+        // ...but not past the http(s) limit
+        let too_long_https = format!(
+            "https://example.com/{}",
+            "a".repeat(PubkyAppPost::MAX_HTTP_ATTACHMENT_URL_LENGTH)
+        );
+        let post = with_attachment(too_long_https);
+        let err = post.validate(Some(&post.create_id())).unwrap_err();
+        assert!(err.contains("maximum length for https://"), "{err}");
+
+        // A pubky URL of the same length is over its own limit
+        let too_long_pubky = format!(
+            "pubky://operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo/pub/pubky.app/files/{}",
+            "a".repeat(PubkyAppPost::MAX_ATTACHMENT_URL_LENGTH)
+        );
+        let post = with_attachment(too_long_pubky);
+        let err = post.validate(Some(&post.create_id())).unwrap_err();
+        assert!(err.contains("maximum length for pubky://"), "{err}");
+    }
+
     #[test]
     fn test_validate_attachments_empty_url() {
         // Create post directly without sanitization to test validation logic
@@ -988,6 +1552,10 @@ mod tests {
             parent: None,
             embed: None,
             attachments: Some(vec!["   ".to_string()]), // Whitespace only
+            mentions: None,
+            schema_version: None,
+            #[cfg(feature = "unknown-fields")]
+            extra: Default::default(),
         };
 
         let id = post.create_id();
@@ -1161,6 +1729,10 @@ mod tests {
             parent: None,
             embed: None,
             attachments: None,
+            mentions: None,
+            schema_version: None,
+            #[cfg(feature = "unknown-fields")]
+            extra: Default::default(),
         };
         let id = post.create_id();
         let result = post.validate(Some(&id));
@@ -1192,6 +1764,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_postkind_all_round_trips() {
+        assert!(!PubkyAppPostKind::all().contains(&PubkyAppPostKind::Unknown));
+        for kind in PubkyAppPostKind::all() {
+            assert!(kind.is_known());
+            assert_eq!(
+                &PubkyAppPostKind::from_str(&kind.to_string()).unwrap(),
+                kind
+            );
+            assert_eq!(
+                &PubkyAppPostKind::from_str_or_unknown(&kind.to_string()),
+                kind
+            );
+        }
+    }
+
+    #[test]
+    fn test_postkind_from_str_or_unknown() {
+        assert_eq!(
+            PubkyAppPostKind::from_str_or_unknown("totally-new-kind"),
+            PubkyAppPostKind::Unknown
+        );
+        assert_eq!(
+            PubkyAppPostKind::from_str_or_unknown("image"),
+            PubkyAppPostKind::Image
+        );
+    }
+
     #[test]
     fn test_is_known_returns_false_for_unknown() {
         assert!(!PubkyAppPostKind::Unknown.is_known());
@@ -1228,6 +1828,10 @@ mod tests {
                 uri: "pubky://x/pub/pubky.app/posts/01".to_string(),
             }),
             attachments: None,
+            mentions: None,
+            schema_version: None,
+            #[cfg(feature = "unknown-fields")]
+            extra: Default::default(),
         };
         let id = post.create_id();
         let result = post.validate(Some(&id));
@@ -1249,10 +1853,22 @@ mod tests {
             parent: None,
             embed: None,
             attachments: None,
+            mentions: None,
+            schema_version: None,
+            #[cfg(feature = "unknown-fields")]
+            extra: Default::default(),
         };
         assert_eq!(post.kind(), "Unknown");
     }
 
+    #[cfg(target_arch = "wasm32")]
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn test_postkind_wasm_kind_enum_getter() {
+        let post = PubkyAppPost::new("x".to_string(), PubkyAppPostKind::Image, None, None, None);
+        assert_eq!(post.kind_enum(), PubkyAppPostKind::Image);
+        assert_eq!(post.kind(), "Image");
+    }
+
     // ----- v0.5.0 Collection variant + PubkyAppCollectionContent envelope -----
 
     fn collection_envelope_json(name: &str, description: Option<&str>, items: &[String]) -> String {
@@ -1782,6 +2398,10 @@ mod tests {
             parent: None,
             embed: None,
             attachments: None,
+            mentions: None,
+            schema_version: None,
+            #[cfg(feature = "unknown-fields")]
+            extra: Default::default(),
         };
         assert_eq!(post.kind(), "Collection");
     }
@@ -1822,4 +2442,635 @@ mod tests {
             Some("https://example.com/cover.png")
         );
     }
+
+    #[test]
+    fn test_schema_version_missing_treated_as_v1() {
+        let post_json = r#"{"content": "Hello", "kind": "short"}"#;
+
+        let raw: PubkyAppPost = serde_json::from_str(post_json).unwrap();
+        assert_eq!(raw.schema_version, None);
+
+        let post = PubkyAppPost::new(
+            "Hello".to_string(),
+            PubkyAppPostKind::Short,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(post.schema_version, Some(CURRENT_SCHEMA_VERSION));
+        let id = post.create_id();
+        let parsed = <PubkyAppPost as Validatable>::try_from(post_json.as_bytes(), &id).unwrap();
+        assert_eq!(parsed.schema_version, Some(CURRENT_SCHEMA_VERSION));
+    }
+
+    #[test]
+    fn test_schema_version_future_rejected() {
+        let post = PubkyAppPost {
+            schema_version: Some(CURRENT_SCHEMA_VERSION + 1),
+            ..PubkyAppPost::new(
+                "Hello".to_string(),
+                PubkyAppPostKind::Short,
+                None,
+                None,
+                None,
+            )
+        };
+        let id = post.create_id();
+        let result = post.validate(Some(&id));
+        assert!(result.unwrap_err().contains("Unsupported schema version"));
+    }
+
+    #[test]
+    fn test_default_fails_validation() {
+        // Pin the placeholder behavior of the derived `Default`
+        let post = PubkyAppPost::default();
+        assert_eq!(post.kind, PubkyAppPostKind::Short);
+        assert!(post.content.is_empty());
+        let id = post.create_id();
+        let result = post.validate(Some(&id));
+        assert!(result.unwrap_err().contains("must have content"));
+    }
+
+    #[test]
+    fn test_empty_short() {
+        let mut post = PubkyAppPost::empty_short();
+        assert_eq!(post.kind, PubkyAppPostKind::Short);
+        assert!(post.content.is_empty());
+        assert!(post.parent.is_none() && post.embed.is_none() && post.attachments.is_none());
+        let id = post.create_id();
+        assert!(post.validate(Some(&id)).is_err());
+
+        post.content = "Now with content".to_string();
+        assert!(post.validate(Some(&id)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_strict_embed() {
+        let embed_post = |kind: PubkyAppPostKind, uri: String| {
+            PubkyAppPost::new(
+                "Valid content".to_string(),
+                PubkyAppPostKind::Short,
+                None,
+                Some(PubkyAppPostEmbed { kind, uri }),
+                None,
+            )
+        };
+        let post_uri = format!("pubky://{TEST_PUBKY_ID}{PUBLIC_PATH}{APP_PATH}posts/0032SSN7Q4EVG");
+        let file_uri = format!("pubky://{TEST_PUBKY_ID}{PUBLIC_PATH}{APP_PATH}files/0032SSN7Q4EVG");
+        let follow_uri =
+            format!("pubky://{TEST_PUBKY_ID}{PUBLIC_PATH}{APP_PATH}follows/{TEST_PUBKY_ID}");
+
+        // Embedded post with any known kind, or an external link, is fine
+        let post = embed_post(PubkyAppPostKind::Long, post_uri.clone());
+        assert!(post.validate_strict(Some(&post.create_id())).is_ok());
+        let post = embed_post(PubkyAppPostKind::Unknown, post_uri);
+        assert!(post
+            .validate_strict(Some(&post.create_id()))
+            .unwrap_err()
+            .contains("embed kind unknown does not match"));
+        let post = embed_post(PubkyAppPostKind::Link, "https://example.com".into());
+        assert!(post.validate_strict(Some(&post.create_id())).is_ok());
+
+        // Files must be embedded with a media kind
+        let post = embed_post(PubkyAppPostKind::Image, file_uri.clone());
+        assert!(post.validate_strict(Some(&post.create_id())).is_ok());
+        let post = embed_post(PubkyAppPostKind::Short, file_uri);
+        assert!(post.validate(Some(&post.create_id())).is_ok());
+        assert!(post
+            .validate_strict(Some(&post.create_id()))
+            .unwrap_err()
+            .contains("embed kind short does not match"));
+
+        // Other pubky resources cannot be embedded
+        let post = embed_post(PubkyAppPostKind::Short, follow_uri);
+        assert!(post.validate_strict(Some(&post.create_id())).is_err());
+
+        // Empty and unparseable uris are rejected
+        let post = embed_post(PubkyAppPostKind::Short, "  ".into());
+        assert_eq!(
+            post.validate_strict(Some(&post.create_id())).unwrap_err(),
+            "Validation Error: embed uri cannot be empty"
+        );
+        let post = embed_post(PubkyAppPostKind::Short, "invalid uri".into());
+        assert!(post
+            .validate_strict(Some(&post.create_id()))
+            .unwrap_err()
+            .contains("Invalid embed URI format"));
+    }
+
+    #[test]
+    fn test_is_self_reply() {
+        const OTHER_PUBKY_ID: &str = "pxnu33x7jtpx9ar1ytsi4yxbp6a5o36gwhffs8zoxmbuptici1jy";
+        let author = PubkyId::try_from(TEST_PUBKY_ID).unwrap();
+        let reply_to = |parent: Option<String>| {
+            PubkyAppPost::new(
+                "Reply".to_string(),
+                PubkyAppPostKind::Short,
+                parent,
+                None,
+                None,
+            )
+        };
+
+        let own_parent = post_uri_builder(TEST_PUBKY_ID.into(), "0032SSN7Q4EVG".into());
+        assert_eq!(
+            reply_to(Some(own_parent)).is_self_reply(&author),
+            Some(true)
+        );
+
+        let other_parent = post_uri_builder(OTHER_PUBKY_ID.into(), "0032SSN7Q4EVG".into());
+        assert_eq!(
+            reply_to(Some(other_parent)).is_self_reply(&author),
+            Some(false)
+        );
+
+        assert_eq!(reply_to(None).is_self_reply(&author), None);
+        assert_eq!(
+            reply_to(Some("https://example.com/post".into())).is_self_reply(&author),
+            None
+        );
+    }
+
+    #[test]
+    fn test_is_emoji_only() {
+        let short = |content: &str| {
+            PubkyAppPost::new(
+                content.to_string(),
+                PubkyAppPostKind::Short,
+                None,
+                None,
+                None,
+            )
+        };
+
+        assert!(short("🔥🔥").is_emoji_only());
+        assert!(short("  🔥 🚀  ").is_emoji_only());
+        // ZWJ sequence, skin tone and flag
+        assert!(short("👩‍💻👍🏽🇨🇭").is_emoji_only());
+        assert!(short("❤️").is_emoji_only());
+
+        assert!(!short("🔥 hot").is_emoji_only());
+        assert!(!short("hot").is_emoji_only());
+        assert!(!short("123").is_emoji_only());
+        assert!(!short("").is_emoji_only());
+        // Joiners alone are not emoji
+        assert!(!short("\u{200D}\u{FE0F}").is_emoji_only());
+    }
+
+    #[test]
+    fn test_mentions() {
+        let with_mentions = |mentions: Vec<String>| {
+            PubkyAppPost {
+                mentions: Some(mentions),
+                ..PubkyAppPost::new(
+                    "Hello friends".to_string(),
+                    PubkyAppPostKind::Short,
+                    None,
+                    None,
+                    None,
+                )
+            }
+            .sanitize()
+        };
+
+        // Valid ids are kept, trimmed
+        let post = with_mentions(vec![format!("  {TEST_PUBKY_ID} ")]);
+        assert_eq!(post.mentions, Some(vec![TEST_PUBKY_ID.to_string()]));
+        assert!(post.validate(Some(&post.create_id())).is_ok());
+
+        // Invalid ids are dropped by sanitization
+        let post = with_mentions(vec![
+            "not-a-pubky-id".to_string(),
+            TEST_PUBKY_ID.to_string(),
+        ]);
+        assert_eq!(post.mentions, Some(vec![TEST_PUBKY_ID.to_string()]));
+
+        // ...and rejected by validation when sanitization is bypassed
+        let raw = PubkyAppPost {
+            mentions: Some(vec!["not-a-pubky-id".to_string()]),
+            ..post.clone()
+        };
+        let err = raw.validate(None).unwrap_err();
+        assert!(err.contains("Invalid mention"), "{err}");
+
+        // Over the limit
+        let post = with_mentions(vec![
+            TEST_PUBKY_ID.to_string();
+            PubkyAppPost::MAX_MENTIONS + 1
+        ]);
+        let err = post.validate(None).unwrap_err();
+        assert!(err.contains("Too many mentions"), "{err}");
+
+        // The field is optional and omitted when unset
+        let json = serde_json::to_value(PubkyAppPost::empty_short()).unwrap();
+        assert!(json.get("mentions").is_none());
+    }
+
+    #[test]
+    fn test_pubky_attachments() {
+        let file_uri = format!("pubky://{TEST_PUBKY_ID}{PUBLIC_PATH}{APP_PATH}files/0034A0X7NJ52G");
+        let post = PubkyAppPost::new(
+            "With files".to_string(),
+            PubkyAppPostKind::Image,
+            None,
+            None,
+            Some(vec![
+                "https://example.com/cat.png".to_string(),
+                file_uri.clone(),
+            ]),
+        );
+
+        let resolved = post.pubky_attachments();
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0], ParsedUri::try_from(file_uri.as_str()).unwrap());
+        assert_eq!(
+            resolved[0].resource,
+            Resource::File("0034A0X7NJ52G".to_string())
+        );
+
+        assert!(PubkyAppPost::empty_short().pubky_attachments().is_empty());
+    }
+
+    #[test]
+    fn test_attachment_kinds() {
+        let file_uri = format!("pubky://{TEST_PUBKY_ID}{PUBLIC_PATH}{APP_PATH}files/0034A0X7NJ52G");
+        let post = PubkyAppPost::new(
+            "With files".to_string(),
+            PubkyAppPostKind::Image,
+            None,
+            None,
+            Some(vec![
+                "https://example.com/cat.PNG?size=large".to_string(),
+                file_uri,
+                "https://example.com/clip.mp4".to_string(),
+                "https://example.com/report".to_string(),
+            ]),
+        );
+
+        assert_eq!(
+            post.attachment_kinds(),
+            vec![
+                PubkyAppPostKind::Image,
+                PubkyAppPostKind::File,
+                PubkyAppPostKind::Video,
+                PubkyAppPostKind::File,
+            ]
+        );
+        assert!(PubkyAppPost::empty_short().attachment_kinds().is_empty());
+    }
+
+    #[test]
+    fn test_embed_from_post() {
+        let author = PubkyId::try_from(TEST_PUBKY_ID).unwrap();
+        let embed = PubkyAppPostEmbed::from_post(&author, "0032SSN7Q4EVG");
+        assert_eq!(embed.kind, PubkyAppPostKind::Short);
+        assert_eq!(
+            embed.uri,
+            post_uri_builder(TEST_PUBKY_ID.into(), "0032SSN7Q4EVG".into())
+        );
+
+        let post = PubkyAppPost::new(
+            "Quoting".to_string(),
+            PubkyAppPostKind::Short,
+            None,
+            Some(embed),
+            None,
+        );
+        assert!(post.validate(Some(&post.create_id())).is_ok());
+    }
+
+    #[test]
+    fn test_embed_target() {
+        let post_id = "0032SSN7Q4EVG";
+        let post_uri = format!("pubky://{TEST_PUBKY_ID}{PUBLIC_PATH}{APP_PATH}posts/{post_id}");
+        let embed_post = |uri: &str| {
+            PubkyAppPost::new(
+                "Quoting".to_string(),
+                PubkyAppPostKind::Short,
+                None,
+                Some(PubkyAppPostEmbed {
+                    kind: PubkyAppPostKind::Short,
+                    uri: uri.to_string(),
+                }),
+                None,
+            )
+        };
+
+        assert!(PubkyAppPost::empty_short().embed_target().is_none());
+
+        let target = embed_post(&post_uri).embed_target().unwrap().unwrap();
+        assert_eq!(target.user_id.to_string(), TEST_PUBKY_ID);
+        assert_eq!(target.resource, Resource::Post(post_id.to_string()));
+        // A client can detect that post `post_id` by this author embeds itself
+        assert_eq!(target, ParsedUri::try_from(post_uri.as_str()).unwrap());
+
+        assert!(embed_post("https://example.com")
+            .embed_target()
+            .unwrap()
+            .is_err());
+    }
+
+    #[test]
+    fn test_self_uri() {
+        let author = PubkyId::try_from(TEST_PUBKY_ID).unwrap();
+        let post = PubkyAppPost::new(
+            "Hello".to_string(),
+            PubkyAppPostKind::Short,
+            None,
+            None,
+            None,
+        );
+        let id = post.create_id();
+
+        let uri = PubkyAppPost::self_uri(&author, &id);
+        assert_eq!(uri, post_uri_builder(TEST_PUBKY_ID.into(), id.clone()));
+        assert_eq!(
+            ParsedUri::try_from(uri.as_str()).unwrap().resource,
+            Resource::Post(id)
+        );
+    }
+
+    #[test]
+    fn test_bidi_override_stripped_and_rejected() {
+        let spoofed = "Invoice for \u{202E}fdp.exe".to_string();
+        let post = PubkyAppPost::new(spoofed.clone(), PubkyAppPostKind::Short, None, None, None);
+        assert_eq!(post.content, "Invoice for fdp.exe");
+        assert!(post.validate(Some(&post.create_id())).is_ok());
+
+        // Directly constructed objects bypass sanitize and are rejected
+        let post = PubkyAppPost {
+            content: spoofed,
+            ..PubkyAppPost::empty_short()
+        };
+        assert!(post
+            .validate(Some(&post.create_id()))
+            .unwrap_err()
+            .contains("bidi-control or zero-width"));
+    }
+
+    #[test]
+    fn test_control_chars_stripped_and_rejected() {
+        let content = "Hello\0 world\n\tindented\u{7}".to_string();
+        let post = PubkyAppPost::new(content.clone(), PubkyAppPostKind::Short, None, None, None);
+        assert_eq!(post.content, "Hello world\n\tindented");
+        assert!(post.validate(Some(&post.create_id())).is_ok());
+
+        // Directly constructed objects bypass sanitize and are rejected
+        let post = PubkyAppPost {
+            content,
+            ..PubkyAppPost::empty_short()
+        };
+        assert!(post
+            .validate(Some(&post.create_id()))
+            .unwrap_err()
+            .contains("control characters"));
+    }
+
+    #[test]
+    fn test_sanitize_normalizes_line_endings() {
+        let content = "windows\r\nold mac\runix\nend".to_string();
+        let post = PubkyAppPost::new(content.clone(), PubkyAppPostKind::Long, None, None, None);
+        assert_eq!(post.content, "windows\nold mac\nunix\nend");
+        // The lone `\r` becomes a newline instead of being stripped
+        assert_eq!(post.content.lines().count(), 4);
+        assert_eq!(post.content.chars().count(), content.chars().count() - 1);
+        assert_eq!(post.content.len(), content.len() - 1);
+        assert!(post.validate(Some(&post.create_id())).is_ok());
+    }
+
+    #[test]
+    fn test_duplicate_attachments_rejected() {
+        let file = format!("pubky://{TEST_PUBKY_ID}{PUBLIC_PATH}{APP_PATH}files/0034A0X7Q3D80");
+        let with_attachments = |attachments: Vec<String>| {
+            PubkyAppPost::new(
+                "With attachments".to_string(),
+                PubkyAppPostKind::Image,
+                None,
+                None,
+                Some(attachments),
+            )
+        };
+
+        let distinct = with_attachments(vec![
+            file.clone(),
+            "https://example.com/cat.png".to_string(),
+        ]);
+        assert!(distinct.validate(Some(&distinct.create_id())).is_ok());
+
+        let duplicated = with_attachments(vec![
+            "https://example.com/cat.png".to_string(),
+            file,
+            "HTTPS://EXAMPLE.com/cat.png".to_string(),
+        ]);
+        assert_eq!(
+            duplicated
+                .validate(Some(&duplicated.create_id()))
+                .unwrap_err(),
+            "Validation Error: Duplicate attachment URL at index 2"
+        );
+    }
+
+    #[test]
+    fn test_sanitize_preserves_attachment_order() {
+        let first = format!("pubky://{TEST_PUBKY_ID}{PUBLIC_PATH}{APP_PATH}files/0034A0X7Q3D80");
+        let last = format!("pubky://{TEST_PUBKY_ID}{PUBLIC_PATH}{APP_PATH}files/0034A0X7Q3D81");
+        let post = PubkyAppPost::new(
+            "With attachments".to_string(),
+            PubkyAppPostKind::Image,
+            None,
+            None,
+            Some(vec![
+                format!("  {first}  "),
+                "  not a url ".to_string(),
+                last.clone(),
+            ]),
+        );
+
+        // The invalid middle entry stays in place; survivors keep their order
+        assert_eq!(
+            post.attachments,
+            Some(vec![first.clone(), "not a url".to_string(), last.clone()])
+        );
+        assert!(post
+            .validate(Some(&post.create_id()))
+            .unwrap_err()
+            .contains("index 1"));
+
+        let post = PubkyAppPost::new(
+            "With attachments".to_string(),
+            PubkyAppPostKind::Image,
+            None,
+            None,
+            Some(vec![last.clone(), first.clone()]),
+        );
+        assert_eq!(post.attachments, Some(vec![last, first]));
+    }
+
+    #[test]
+    fn test_validate_media_caption_length() {
+        let caption_post = |kind: PubkyAppPostKind, len: usize| {
+            PubkyAppPost::new("a".repeat(len), kind, None, None, None)
+        };
+        // Media captions are no longer bound by the Short limit
+        let image = caption_post(
+            PubkyAppPostKind::Image,
+            PubkyAppPost::MAX_SHORT_CONTENT_LENGTH + 1,
+        );
+        assert!(image.validate(Some(&image.create_id())).is_ok());
+
+        for kind in [
+            PubkyAppPostKind::Image,
+            PubkyAppPostKind::Video,
+            PubkyAppPostKind::Link,
+            PubkyAppPostKind::File,
+        ] {
+            let at_limit = caption_post(kind.clone(), PubkyAppPost::MAX_MEDIA_CAPTION_LENGTH);
+            assert!(at_limit.validate(Some(&at_limit.create_id())).is_ok());
+
+            let over = caption_post(kind, PubkyAppPost::MAX_MEDIA_CAPTION_LENGTH + 1);
+            let err = over.validate(Some(&over.create_id())).unwrap_err();
+            assert!(err.contains("Image/Video/Link/File"));
+        }
+
+        // Short posts keep their own, lower bound
+        let short = caption_post(
+            PubkyAppPostKind::Short,
+            PubkyAppPost::MAX_SHORT_CONTENT_LENGTH + 1,
+        );
+        assert!(short.validate(Some(&short.create_id())).is_err());
+    }
+
+    #[test]
+    fn test_validate_content_max_bytes() {
+        // 4-byte emoji: within the Long char limit but over the byte limit
+        let emoji_count = PubkyAppPost::MAX_CONTENT_BYTES / 4 + 1;
+        assert!(emoji_count <= PubkyAppPost::MAX_LONG_CONTENT_LENGTH);
+        let post = PubkyAppPost::new(
+            "🔥".repeat(emoji_count),
+            PubkyAppPostKind::Long,
+            None,
+            None,
+            None,
+        );
+        assert!(post
+            .validate(Some(&post.create_id()))
+            .unwrap_err()
+            .contains("exceeds maximum size"));
+
+        let post = PubkyAppPost::new(
+            "🔥".repeat(PubkyAppPost::MAX_CONTENT_BYTES / 4),
+            PubkyAppPostKind::Long,
+            None,
+            None,
+            None,
+        );
+        assert!(post.validate(Some(&post.create_id())).is_ok());
+    }
+
+    #[test]
+    fn test_validate_strict_parent_equals_embed() {
+        let uri = |id: &str| format!("pubky://{TEST_PUBKY_ID}{PUBLIC_PATH}{APP_PATH}posts/{id}");
+        let reply_quoting = |parent: String, embed: String| PubkyAppPost {
+            content: "Replying and quoting".to_string(),
+            kind: PubkyAppPostKind::Short,
+            parent: Some(parent),
+            embed: Some(PubkyAppPostEmbed {
+                kind: PubkyAppPostKind::Short,
+                uri: embed,
+            }),
+            attachments: None,
+            mentions: None,
+            schema_version: None,
+            #[cfg(feature = "unknown-fields")]
+            extra: Default::default(),
+        };
+
+        // Identical after normalization (surrounding whitespace)
+        let post = reply_quoting(uri("0032SSN7Q4EVG"), format!(" {} ", uri("0032SSN7Q4EVG")));
+        assert!(post.validate(Some(&post.create_id())).is_ok());
+        assert!(post
+            .validate_strict(Some(&post.create_id()))
+            .unwrap_err()
+            .contains("parent and embed must not reference the same URI"));
+
+        let post = reply_quoting(uri("0032SSN7Q4EVG"), uri("0032SSN7Q4EVH"));
+        assert!(post.validate_strict(Some(&post.create_id())).is_ok());
+    }
+
+    #[test]
+    fn test_sanitize_ascii_trim_preserves_ideographic_space() {
+        let content = "\n\u{3000}\n\u{3000}本文です。\u{3000}\n  ".to_string();
+        let post = PubkyAppPost {
+            content: content.clone(),
+            kind: PubkyAppPostKind::Long,
+            ..PubkyAppPost::empty_short()
+        };
+
+        assert_eq!(
+            post.clone().sanitize_ascii_trim().content,
+            "\u{3000}\n\u{3000}本文です。\u{3000}"
+        );
+        // The default sanitize trims Unicode whitespace
+        assert_eq!(post.sanitize().content, "\u{3000}本文です。");
+
+        // Other kinds are sanitized as usual
+        let short = PubkyAppPost {
+            content,
+            ..PubkyAppPost::empty_short()
+        };
+        assert_eq!(short.sanitize_ascii_trim().content, "本文です。");
+    }
+
+    #[test]
+    fn test_sanitize_long_preserves_interior_content() {
+        let body = "    indented code block\n\n```\n\n  fenced with blank lines  \n\n```";
+        let post = PubkyAppPost::new(
+            format!("\n  \n{body}\n\n  \n"),
+            PubkyAppPostKind::Long,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(post.content, body);
+
+        // Other kinds keep trimming all outer whitespace
+        let post = PubkyAppPost::new(
+            format!("\n{body}\n"),
+            PubkyAppPostKind::Short,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(post.content, body.trim_start());
+    }
+
+    #[test]
+    fn test_content_fingerprint() {
+        let post = |content: &str| {
+            PubkyAppPost::new(
+                content.to_string(),
+                PubkyAppPostKind::Short,
+                None,
+                None,
+                None,
+            )
+        };
+        let first = post("Buy now!");
+        let second = post("  Buy now!  ");
+        assert_eq!(first.content_fingerprint(), second.content_fingerprint());
+        assert_eq!(first.content_fingerprint().len(), 26);
+
+        assert_ne!(
+            first.content_fingerprint(),
+            post("Buy later!").content_fingerprint()
+        );
+        let long = PubkyAppPost::new(
+            "Buy now!".to_string(),
+            PubkyAppPostKind::Long,
+            None,
+            None,
+            None,
+        );
+        assert_ne!(first.content_fingerprint(), long.content_fingerprint());
+    }
 }
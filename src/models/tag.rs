@@ -1,10 +1,12 @@
 use crate::{
-    common::timestamp,
+    common::{blake3_crockford_id, sanitize_pubky_uri, timestamp_micros, validate_created_at},
     limits::VALIDATION_LIMITS,
-    traits::{HasIdPath, HashId, Validatable},
-    APP_PATH, PUBLIC_PATH,
+    traits::{HasIdPath, HashId, PutPayload, ResourceModel, Validatable},
+    ParsedUri, PubkyAppPost, Resource, ResourceKind, APP_PATH, PUBLIC_PATH,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use unicode_normalization::UnicodeNormalization;
 use url::Url;
 
 #[cfg(target_arch = "wasm32")]
@@ -32,12 +34,13 @@ pub struct PubkyAppTag {
     pub uri: String,
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
     pub label: String,
+    /// Creation time in microseconds since the UNIX epoch.
     pub created_at: i64,
 }
 
 impl PubkyAppTag {
     pub fn new(uri: String, label: String) -> Self {
-        let created_at = timestamp();
+        let created_at = timestamp_micros();
         Self {
             uri,
             label,
@@ -45,6 +48,40 @@ impl PubkyAppTag {
         }
         .sanitize()
     }
+
+    /// Returns `true` if the tag is on a post and `target`, the post fetched
+    /// from its `uri`, is the `[DELETED]` tombstone. Clients can use it to hide
+    /// tags orphaned by a deletion.
+    pub fn points_at_deleted(&self, target: &PubkyAppPost) -> bool {
+        let on_post = ParsedUri::try_from(self.uri.as_str())
+            .is_ok_and(|uri| matches!(uri.resource, Resource::Post(_)));
+        on_post && target.is_deleted()
+    }
+}
+
+impl PubkyAppTag {
+    /// Returns the tag with a `created_at` written in seconds or milliseconds
+    /// scaled up to microseconds, guessing the unit from its magnitude.
+    ///
+    /// For importing legacy tags; `validate` rejects timestamps that are not
+    /// in microseconds. Values that already look like microseconds are kept.
+    pub fn normalize_created_at(mut self) -> Self {
+        const MAX_SECONDS: i64 = 100_000_000_000; // year 5138 in seconds
+        const MAX_MILLIS: i64 = 100_000_000_000_000; // year 5138 in millis
+        if (0..MAX_SECONDS).contains(&self.created_at) {
+            self.created_at *= 1_000_000;
+        } else if (0..MAX_MILLIS).contains(&self.created_at) {
+            self.created_at *= 1_000;
+        }
+        self
+    }
+}
+
+/// Label and tagged-URI length limits.
+impl PubkyAppTag {
+    pub const MIN_LABEL_LENGTH: usize = VALIDATION_LIMITS.tag_label_min_length;
+    pub const MAX_LABEL_LENGTH: usize = VALIDATION_LIMITS.tag_label_max_length;
+    pub const MAX_URI_LENGTH: usize = VALIDATION_LIMITS.tag_uri_max_length;
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -85,6 +122,31 @@ impl HasIdPath for PubkyAppTag {
     }
 }
 
+impl PutPayload for PubkyAppTag {
+    fn put_path(&self, id: Option<&str>) -> Result<String, String> {
+        let id = id.map_or_else(|| self.create_id(), String::from);
+        Ok(Self::create_path(&id))
+    }
+}
+
+impl ResourceModel for PubkyAppTag {
+    const KIND: ResourceKind = ResourceKind::Tag;
+}
+
+impl PubkyAppTag {
+    /// Returns the label in the aggressive form of
+    /// `sanitize_tag_label_aggressive`, for grouping label variants.
+    pub fn canonical_label(&self) -> String {
+        sanitize_tag_label_aggressive(&self.label)
+    }
+
+    /// Like `create_id`, but hashes the canonical label, so tags whose labels
+    /// only differ by separators share an id. Not a homeserver path id.
+    pub fn create_canonical_id(&self) -> String {
+        blake3_crockford_id(format!("{}:{}", self.uri, self.canonical_label()).as_bytes())
+    }
+}
+
 impl HashId for PubkyAppTag {
     /// Tag ID is created based on the hash of the URI tagged and the label used
     fn get_id_data(&self) -> String {
@@ -92,10 +154,24 @@ impl HashId for PubkyAppTag {
     }
 }
 
-/// Sanitizes a single tag label by trimming whitespace and converting to lowercase.
+/// Sanitizes a single tag label by trimming whitespace, converting to lowercase
+/// and normalizing to Unicode NFC, so visually identical labels hash to the same id.
 /// This function is public so it can be reused by other models that use tags (e.g., Feed).
 pub fn sanitize_tag_label(tag: &str) -> String {
-    tag.trim().to_lowercase()
+    tag.trim().to_lowercase().nfc().collect()
+}
+
+/// Opt-in, more aggressive form of `sanitize_tag_label` that also removes
+/// hyphens and underscores, so `c-plus-plus`, `c_plus_plus` and `cplusplus`
+/// collapse to the same key.
+///
+/// Only meant for grouping; the stored label and tag id keep the regular
+/// sanitized form, which is what clients display.
+pub fn sanitize_tag_label_aggressive(tag: &str) -> String {
+    sanitize_tag_label(tag)
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_'))
+        .collect()
 }
 
 /// Validates a single tag label according to PubkyAppTag rules.
@@ -139,18 +215,51 @@ pub fn validate_tag_label(tag: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// Counts occurrences of each label across the given tags.
+///
+/// Labels are passed through `sanitize_tag_label` before counting, so
+/// `"Bitcoin"` and `" bitcoin "` are aggregated under the same key. Useful
+/// for indexers building "top tags" views.
+pub fn count_labels<'a, I: IntoIterator<Item = &'a PubkyAppTag>>(
+    tags: I,
+) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    for tag in tags {
+        *counts.entry(sanitize_tag_label(&tag.label)).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Returns the distinct labels applied to `uri` among the given tags, in
+/// first-seen order.
+///
+/// Pass the tags written by a single author to check how many labels they
+/// have already applied to a resource, e.g. to enforce a per-post tag limit
+/// client-side. Labels are passed through `sanitize_tag_label` and `uri`
+/// through the same normalization as tag sanitization.
+pub fn labels_for_uri<'a, I: IntoIterator<Item = &'a PubkyAppTag>>(
+    tags: I,
+    uri: &str,
+) -> Vec<String> {
+    let uri = sanitize_pubky_uri(uri);
+    let mut labels: Vec<String> = Vec::new();
+    for tag in tags.into_iter().filter(|tag| tag.uri == uri) {
+        let label = sanitize_tag_label(&tag.label);
+        if !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
+    labels
+}
+
 impl Validatable for PubkyAppTag {
     fn sanitize(self) -> Self {
-        // Sanitize label: trim whitespace and lowercase
+        // Sanitize label: trim whitespace, lowercase and NFC-normalize
         let label = sanitize_tag_label(&self.label);
 
-        // Sanitize URI
-        let uri = match Url::parse(&self.uri) {
-            // If the URL is valid, reformat it to a sanitized string representation
-            Ok(url) => url.to_string(),
-            // If the URL is invalid, return as-is for error reporting later
-            Err(_) => self.uri.trim().to_string(),
-        };
+        // Sanitize URI: normalize it, adding the `pubky://` scheme if it is
+        // missing. Invalid URIs are kept as-is for error reporting later.
+        let uri = sanitize_pubky_uri(&self.uri);
 
         PubkyAppTag {
             uri,
@@ -165,9 +274,19 @@ impl Validatable for PubkyAppTag {
             self.validate_id(id)?;
         }
 
+        validate_created_at(self.created_at)?;
+
         // Validate label
         validate_tag_label(&self.label)?;
 
+        // Validate URI length
+        if self.uri.chars().count() > VALIDATION_LIMITS.tag_uri_max_length {
+            return Err(format!(
+                "Validation Error: URI exceeds maximum length (max: {} characters)",
+                VALIDATION_LIMITS.tag_uri_max_length
+            ));
+        }
+
         // Validate URI format
         Url::parse(&self.uri)
             .map(|_| ())
@@ -180,6 +299,29 @@ mod tests {
     use super::*;
     use crate::{post_uri_builder, traits::Validatable, user_uri_builder, APP_PATH};
 
+    #[test]
+    fn test_points_at_deleted() {
+        let post_uri = post_uri_builder(
+            "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".into(),
+            "0032SSN7Q4EVG".into(),
+        );
+        let tombstone = PubkyAppPost {
+            content: "[DELETED]".to_string(),
+            ..PubkyAppPost::empty_short()
+        };
+        let live = PubkyAppPost {
+            content: "Hello".to_string(),
+            ..PubkyAppPost::empty_short()
+        };
+
+        let tag = PubkyAppTag::new(post_uri.clone(), "cool".to_string());
+        assert!(tag.points_at_deleted(&tombstone));
+        assert!(!tag.points_at_deleted(&live));
+
+        let on_url = PubkyAppTag::new("https://example.com".to_string(), "cool".to_string());
+        assert!(!on_url.points_at_deleted(&tombstone));
+    }
+
     #[test]
     fn test_label_id() {
         let post_uri = post_uri_builder("user_id".into(), "post_id".into());
@@ -188,7 +330,7 @@ mod tests {
         // Create new tag
         let tag = PubkyAppTag {
             uri: post_uri.clone(),
-            created_at: 1627849723,
+            created_at: 1733011200000000,
             label: "cool".to_string(),
         };
 
@@ -200,7 +342,7 @@ mod tests {
 
         let wrong_tag = PubkyAppTag {
             uri: post_uri,
-            created_at: 1627849723,
+            created_at: 1733011200000000,
             label: "co0l".to_string(),
         };
 
@@ -212,7 +354,7 @@ mod tests {
     fn test_create_id() {
         let tag = PubkyAppTag {
             uri: "https://example.com/post/1".to_string(),
-            created_at: 1627849723000,
+            created_at: 1733011200000000,
             label: "cool".to_string(),
         };
 
@@ -233,7 +375,7 @@ mod tests {
         assert_eq!(tag.uri, uri);
         assert_eq!(tag.label, label);
         // Check that created_at is recent
-        let now = timestamp();
+        let now = timestamp_micros();
 
         assert!(tag.created_at <= now && tag.created_at >= now - 1_000_000); // within 1 second
     }
@@ -246,7 +388,7 @@ mod tests {
         );
         let tag = PubkyAppTag {
             uri: post_uri,
-            created_at: 1627849723000,
+            created_at: 1733011200000000,
             label: "cool".to_string(),
         };
 
@@ -271,7 +413,7 @@ mod tests {
             let tag = PubkyAppTag {
                 uri: post_uri.clone(),
                 label: input.to_string(),
-                created_at: 1627849723000,
+                created_at: 1733011200000000,
             };
             let sanitized_tag = tag.sanitize();
             assert_eq!(sanitized_tag.label, expected, "Failed for input: {}", input);
@@ -284,7 +426,7 @@ mod tests {
         let tag = PubkyAppTag {
             uri: post_uri,
             label: "cool".to_string(),
-            created_at: 1627849723000,
+            created_at: 1733011200000000,
         };
 
         let id = tag.create_id();
@@ -292,13 +434,38 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_validate_created_at_unit() {
+        let post_uri = post_uri_builder("user_id".into(), "0000000000000".into());
+        let micros = 1733011200000000;
+        let tag_at = |created_at: i64| PubkyAppTag {
+            uri: post_uri.clone(),
+            label: "cool".to_string(),
+            created_at,
+        };
+
+        // Seconds- and millis-looking timestamps are flagged
+        for created_at in [micros / 1_000_000, micros / 1_000] {
+            let err = tag_at(created_at).validate(None).unwrap_err();
+            assert!(err.contains("created_at"), "{err}");
+        }
+        assert!(tag_at(micros).validate(None).is_ok());
+
+        // ...and can be normalized to microseconds explicitly
+        for created_at in [micros / 1_000_000, micros / 1_000, micros] {
+            let tag = tag_at(created_at).normalize_created_at();
+            assert_eq!(tag.created_at, micros);
+            assert!(tag.validate(None).is_ok());
+        }
+    }
+
     #[test]
     fn test_validate_invalid_label_length() {
         let post_uri = post_uri_builder("user_id".into(), "0000000000000".into());
         let tag = PubkyAppTag {
             uri: post_uri,
             label: "a".repeat(VALIDATION_LIMITS.tag_label_max_length + 1),
-            created_at: 1627849723000,
+            created_at: 1733011200000000,
         };
 
         let id = tag.create_id();
@@ -318,7 +485,7 @@ mod tests {
         let tag = PubkyAppTag {
             uri: post_uri,
             label: "cool".to_string(),
-            created_at: 1627849723000,
+            created_at: 1733011200000000,
         };
 
         let invalid_id = "INVALIDID";
@@ -332,7 +499,7 @@ mod tests {
         let tag = PubkyAppTag {
             uri: post_uri,
             label: format!("invalidchar{}", VALIDATION_LIMITS.tag_invalid_chars[0]),
-            created_at: 1627849723000,
+            created_at: 1733011200000000,
         };
 
         let id = tag.create_id();
@@ -345,7 +512,7 @@ mod tests {
         let tag = PubkyAppTag {
             uri: "user_id/pub/pubky.app/posts/post_id".into(),
             label: "cool".to_string(),
-            created_at: 1627849723000,
+            created_at: 1733011200000000,
         };
 
         let id = tag.create_id();
@@ -364,7 +531,7 @@ mod tests {
             {{
                 "uri": "{user_uri}",
                 "label": "{tag_label}",
-                "created_at": 1627849723000
+                "created_at": 1733011200000000
             }}
         "#
         );
@@ -383,7 +550,7 @@ mod tests {
         {
             "uri": "invalid_uri",
             "label": "CoolTag",
-            "created_at": 1627849723000
+            "created_at": 1733011200000000
         }
         "#;
 
@@ -406,7 +573,7 @@ mod tests {
         for label in trim_cases {
             let tag = PubkyAppTag {
                 uri: post_uri.clone(),
-                created_at: 1627849723,
+                created_at: 1733011200000000,
                 label: label.to_string(),
             };
             let sanitized = tag.sanitize();
@@ -421,7 +588,7 @@ mod tests {
         // Internal whitespace cannot be trimmed and should fail validation
         let tag = PubkyAppTag {
             uri: post_uri,
-            created_at: 1627849723,
+            created_at: 1733011200000000,
             label: "   co ol ".to_string(),
         };
         let sanitized = tag.sanitize();
@@ -465,4 +632,118 @@ mod tests {
             VALIDATION_LIMITS.tag_label_max_length
         );
     }
+
+    #[test]
+    fn test_count_labels() {
+        let post_uri = post_uri_builder("user_id".into(), "0000000000000".into());
+        let tags: Vec<PubkyAppTag> = ["Bitcoin", "bitcoin", "  BITCOIN ", "Rust", "rust", "pubky"]
+            .iter()
+            .map(|label| PubkyAppTag {
+                uri: post_uri.clone(),
+                label: label.to_string(),
+                created_at: 1733011200000000,
+            })
+            .collect();
+
+        let counts = count_labels(&tags);
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts.get("bitcoin"), Some(&3));
+        assert_eq!(counts.get("rust"), Some(&2));
+        assert_eq!(counts.get("pubky"), Some(&1));
+
+        // An empty input yields an empty map
+        assert!(count_labels(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_sanitize_tag_label_aggressive() {
+        let post_uri = post_uri_builder("user_id".into(), "0000000000000".into());
+        let tags: Vec<PubkyAppTag> = ["c-plus-plus", "C_Plus_Plus", " cplusplus "]
+            .iter()
+            .map(|label| PubkyAppTag::new(post_uri.clone(), label.to_string()))
+            .collect();
+
+        // The default mode keeps the variants apart, with their display form
+        assert_eq!(tags[0].label, "c-plus-plus");
+        assert_eq!(tags[1].label, "c_plus_plus");
+        assert_ne!(tags[0].create_id(), tags[1].create_id());
+
+        // The aggressive mode unifies them
+        for tag in &tags {
+            assert_eq!(tag.canonical_label(), "cplusplus");
+            assert_eq!(tag.create_canonical_id(), tags[2].create_canonical_id());
+        }
+        assert_eq!(tags[2].create_canonical_id(), tags[2].create_id());
+
+        assert_eq!(sanitize_tag_label_aggressive(" Bit-Coin_ "), "bitcoin");
+    }
+
+    #[test]
+    fn test_sanitize_adds_missing_pubky_scheme() {
+        let tag = PubkyAppTag::new(
+            "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo/pub/pubky.app/posts/0032SSN7Q4EVG"
+                .to_string(),
+            "cool".to_string(),
+        );
+        let expected = post_uri_builder(
+            "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".into(),
+            "0032SSN7Q4EVG".into(),
+        );
+        assert_eq!(tag.uri, expected);
+        assert!(tag.validate(Some(&tag.create_id())).is_ok());
+    }
+
+    #[test]
+    fn test_sanitize_keeps_broken_uri_invalid() {
+        let tag = PubkyAppTag::new("not a uri/at all".to_string(), "cool".to_string());
+        assert_eq!(tag.uri, "not a uri/at all");
+        let result = tag.validate(Some(&tag.create_id()));
+        assert!(result
+            .unwrap_err()
+            .starts_with("Validation Error: Invalid URI format"));
+    }
+
+    #[test]
+    fn test_sanitize_label_nfc() {
+        let uri = user_uri_builder("user_id".into());
+        let nfc = PubkyAppTag::new(uri.clone(), "caf\u{e9}".to_string());
+        let nfd = PubkyAppTag::new(uri, "cafe\u{301}".to_string());
+
+        assert_eq!(nfd.label, "caf\u{e9}");
+        assert_eq!(nfc.label, nfd.label);
+        assert_eq!(nfc.create_id(), nfd.create_id());
+    }
+
+    #[test]
+    fn test_labels_for_uri() {
+        let first = post_uri_builder("user_id".into(), "0032SSN7Q4EVG".into());
+        let second = post_uri_builder("user_id".into(), "0032SSN7Q4EVH".into());
+        let tags = vec![
+            PubkyAppTag::new(first.clone(), "rust".to_string()),
+            PubkyAppTag::new(second.clone(), "pubky".to_string()),
+            PubkyAppTag::new(first.clone(), "wasm".to_string()),
+            PubkyAppTag::new(first.clone(), "Rust".to_string()),
+        ];
+
+        assert_eq!(labels_for_uri(&tags, &first), vec!["rust", "wasm"]);
+        assert_eq!(labels_for_uri(&tags, &second), vec!["pubky"]);
+        assert!(labels_for_uri(&tags, "pubky://other/pub/pubky.app/profile.json").is_empty());
+    }
+
+    #[test]
+    fn test_validate_uri_max_length() {
+        let base = "https://example.com/";
+        let at_limit = format!(
+            "{base}{}",
+            "a".repeat(PubkyAppTag::MAX_URI_LENGTH - base.len())
+        );
+        let tag = PubkyAppTag::new(at_limit.clone(), "cool".to_string());
+        assert!(tag.validate(Some(&tag.create_id())).is_ok());
+
+        let tag = PubkyAppTag::new(format!("{at_limit}a"), "cool".to_string());
+        assert!(tag
+            .validate(Some(&tag.create_id()))
+            .unwrap_err()
+            .contains("URI exceeds maximum length"));
+    }
 }
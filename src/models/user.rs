@@ -1,10 +1,14 @@
 use crate::{
-    common::sanitize_url,
+    common::{
+        is_disallowed_invisible_char, sanitize_url, strip_disallowed_invisible_chars,
+        validate_schema_version, MIN_TIMESTAMP_MICROS,
+    },
     limits::VALIDATION_LIMITS,
-    traits::{HasPath, Validatable},
-    APP_PATH, PUBLIC_PATH,
+    traits::{HasPath, PutPayload, ResourceModel, Validatable},
+    ResourceKind, APP_PATH, CURRENT_SCHEMA_VERSION, PUBLIC_PATH,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use url::Url;
 
 #[cfg(target_arch = "wasm32")]
@@ -31,6 +35,23 @@ pub struct PubkyAppUser {
     pub links: Option<Vec<PubkyAppUserLink>>,
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
     pub status: Option<String>,
+    /// When the `status` stops applying, in microseconds since the UNIX epoch.
+    /// A hint for clients, which may hide an expired status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub status_expires_at: Option<i64>,
+    /// Version of the profile schema that produced this object.
+    /// A missing value means version 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub schema_version: Option<u16>,
+    /// Fields not defined by this version of the spec, kept so objects written
+    /// by a newer schema survive an import/export round trip.
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    #[cfg_attr(feature = "openapi", schema(ignore))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 
 impl Default for PubkyAppUser {
@@ -41,6 +62,10 @@ impl Default for PubkyAppUser {
             image: None,
             links: None,
             status: None,
+            status_expires_at: None,
+            schema_version: None,
+            #[cfg(feature = "unknown-fields")]
+            extra: Default::default(),
         }
         .sanitize()
     }
@@ -70,6 +95,10 @@ impl PubkyAppUser {
     pub fn status(&self) -> Option<String> {
         self.status.clone()
     }
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter, js_name = statusExpiresAt))]
+    pub fn status_expires_at(&self) -> Option<i64> {
+        self.status_expires_at
+    }
 
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromJson))]
     pub fn from_json(js_value: &JsValue) -> Result<Self, String> {
@@ -127,11 +156,170 @@ impl PubkyAppUser {
             image,
             links,
             status,
+            status_expires_at: None,
+            schema_version: None,
+            #[cfg(feature = "unknown-fields")]
+            extra: Default::default(),
         }
         .sanitize()
     }
 }
 
+/// Profile field limits, including the links list as a whole.
+impl PubkyAppUser {
+    pub const MIN_NAME_LENGTH: usize = VALIDATION_LIMITS.user_name_min_length;
+    pub const MAX_NAME_LENGTH: usize = VALIDATION_LIMITS.user_name_max_length;
+    pub const MAX_BIO_LENGTH: usize = VALIDATION_LIMITS.user_bio_max_length;
+    pub const MAX_IMAGE_URL_LENGTH: usize = VALIDATION_LIMITS.user_image_url_max_length;
+    pub const MAX_LINKS: usize = VALIDATION_LIMITS.user_links_max_count;
+    pub const MAX_LINKS_TOTAL_BYTES: usize = VALIDATION_LIMITS.user_links_max_total_bytes;
+    pub const MAX_STATUS_LENGTH: usize = VALIDATION_LIMITS.user_status_max_length;
+}
+
+impl PubkyAppUser {
+    /// Returns the parsed image URL, or `None` if there is no image or it is
+    /// not a valid URL.
+    pub fn image_url(&self) -> Option<Url> {
+        Url::parse(self.image.as_deref()?).ok()
+    }
+
+    /// Reads the intended display size from the `w` and `h` query params of
+    /// the image URL (e.g. `?w=200&h=200`).
+    ///
+    /// Returns `None` if there is no image, it is not a valid URL, or either
+    /// param is missing or not a number. Never affects validation.
+    pub fn image_dimensions(&self) -> Option<(u32, u32)> {
+        let url = self.image_url()?;
+        let param = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .and_then(|(_, value)| value.parse::<u32>().ok())
+        };
+        Some((param("w")?, param("h")?))
+    }
+
+    /// Returns the bio with simple markdown syntax removed, for clients that
+    /// render it as plaintext.
+    ///
+    /// Links and images (`[label](url)`, `![alt](url)`) are replaced by their
+    /// label, and emphasis, strikethrough and code markers are dropped.
+    /// `*` and `_` inside a word (e.g. `snake_case`) are kept.
+    pub fn bio_plaintext(&self) -> Option<String> {
+        self.bio.as_deref().map(strip_markdown)
+    }
+
+    /// Returns `true` if the status has an expiry at or before `now`, in
+    /// microseconds. A status without an expiry never expires.
+    pub fn status_is_expired(&self, now: i64) -> bool {
+        self.status_expires_at
+            .is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// Strips the inline markdown syntax described in [PubkyAppUser::bio_plaintext].
+fn strip_markdown(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+
+        let label_start = match (c, chars.get(i + 1)) {
+            ('[', _) => Some(i + 1),
+            ('!', Some('[')) => Some(i + 2),
+            _ => None,
+        };
+        if let Some(start) = label_start {
+            if let Some((label_end, link_end)) = find_link(&chars, start) {
+                let label: String = chars[start..label_end].iter().collect();
+                out.push_str(&strip_markdown(&label));
+                i = link_end + 1;
+                continue;
+            }
+        }
+
+        if matches!(c, '*' | '_' | '~' | '`') {
+            let run_end = chars[i..]
+                .iter()
+                .position(|&next| next != c)
+                .map_or(chars.len(), |len| i + len);
+            let before = i.checked_sub(1).map(|j| chars[j]);
+            let after = chars.get(run_end).copied();
+            let inside_word = before.is_some_and(char::is_alphanumeric)
+                && after.is_some_and(char::is_alphanumeric);
+            // A single `~` is usually "approximately", not strikethrough
+            let is_marker = match c {
+                '~' => run_end - i >= 2,
+                '`' => true,
+                _ => !inside_word,
+            };
+            if is_marker {
+                i = run_end;
+                continue;
+            }
+        }
+
+        out.push(c);
+        i += 1;
+    }
+    out
+}
+
+/// For a link label starting at `start`, returns the indices of the closing
+/// `]` and of the `)` ending the `(url)` that must immediately follow it.
+fn find_link(chars: &[char], start: usize) -> Option<(usize, usize)> {
+    let label_end = start + chars[start..].iter().position(|&c| c == ']')?;
+    if chars[start..label_end].contains(&'[') || chars.get(label_end + 1) != Some(&'(') {
+        return None;
+    }
+    let url_start = label_end + 2;
+    let link_end = url_start + chars[url_start..].iter().position(|&c| c == ')')?;
+    Some((label_end, link_end))
+}
+
+/// A partial profile update for [PubkyAppUser::merge].
+///
+/// Each field is `None` to leave the current value unchanged. Optional
+/// profile fields take `Some(None)` to clear the value and `Some(Some(..))`
+/// to overwrite it.
+#[derive(Debug, Default, Clone)]
+pub struct PubkyAppUserPatch {
+    pub name: Option<String>,
+    pub bio: Option<Option<String>>,
+    pub image: Option<Option<String>>,
+    pub links: Option<Option<Vec<PubkyAppUserLink>>>,
+    pub status: Option<Option<String>>,
+    pub status_expires_at: Option<Option<i64>>,
+}
+
+impl PubkyAppUser {
+    /// Applies a partial update in place, then re-sanitizes the profile.
+    ///
+    /// Does not validate; call `validate` before publishing the result.
+    pub fn merge(&mut self, patch: PubkyAppUserPatch) {
+        let mut merged = std::mem::take(self);
+        if let Some(name) = patch.name {
+            merged.name = name;
+        }
+        if let Some(bio) = patch.bio {
+            merged.bio = bio;
+        }
+        if let Some(image) = patch.image {
+            merged.image = image;
+        }
+        if let Some(links) = patch.links {
+            merged.links = links;
+        }
+        if let Some(status) = patch.status {
+            merged.status = status;
+        }
+        if let Some(status_expires_at) = patch.status_expires_at {
+            merged.status_expires_at = status_expires_at;
+        }
+        *self = merged.sanitize();
+    }
+}
+
 impl HasPath for PubkyAppUser {
     const PATH_SEGMENT: &'static str = "profile.json";
 
@@ -140,10 +328,22 @@ impl HasPath for PubkyAppUser {
     }
 }
 
+impl PutPayload for PubkyAppUser {
+    fn put_path(&self, _id: Option<&str>) -> Result<String, String> {
+        Ok(Self::create_path())
+    }
+}
+
+impl ResourceModel for PubkyAppUser {
+    const KIND: ResourceKind = ResourceKind::User;
+}
+
 impl Validatable for PubkyAppUser {
     fn sanitize(self) -> Self {
-        // Sanitize name: trim whitespace only
-        let mut name = self.name.trim().to_string();
+        // Sanitize name: strip bidi-control and zero-width characters, then trim whitespace
+        let mut name = strip_disallowed_invisible_chars(&self.name)
+            .trim()
+            .to_string();
 
         // We use username keyword `[DELETED]` for a user whose `profile.json` has been deleted
         // Therefore this is not a valid username.
@@ -157,24 +357,42 @@ impl Validatable for PubkyAppUser {
         // Sanitize image URL
         let image = self.image.map(|i| sanitize_url(&i));
 
-        // Sanitize status: trim whitespace only
+        // Sanitize status: trim whitespace only. An expiry without a status
+        // has nothing to apply to and is dropped.
         let status = self.status.map(|s| s.trim().to_string());
 
-        // Sanitize links: sanitize each link, validation handles format
-        let links = self
-            .links
-            .map(|links_vec| links_vec.into_iter().map(|link| link.sanitize()).collect());
+        // Sanitize links: sanitize each link, validation handles format. Links
+        // to the same normalized URL are dropped, keeping the first occurrence.
+        let links = self.links.map(|links_vec| {
+            let mut seen_urls = HashSet::new();
+            links_vec
+                .into_iter()
+                .map(|link| link.sanitize())
+                .filter(|link| link.url.is_empty() || seen_urls.insert(link.url.clone()))
+                .collect()
+        });
 
         PubkyAppUser {
             name,
             bio,
             image,
             links,
+            status_expires_at: status.as_ref().and(self.status_expires_at),
             status,
+            // Stamp objects that predate the field with the current version
+            schema_version: self.schema_version.or(Some(CURRENT_SCHEMA_VERSION)),
+            #[cfg(feature = "unknown-fields")]
+            extra: self.extra,
         }
     }
 
     fn validate(&self, _id: Option<&str>) -> Result<(), String> {
+        validate_schema_version(self.schema_version)?;
+
+        if self.name.trim().is_empty() {
+            return Err("Validation Error: Name cannot be empty".into());
+        }
+
         // Validate name length
         let name_length = self.name.chars().count();
         if !(VALIDATION_LIMITS.user_name_min_length..=VALIDATION_LIMITS.user_name_max_length)
@@ -182,6 +400,12 @@ impl Validatable for PubkyAppUser {
         {
             return Err("Validation Error: Invalid name length".into());
         }
+        if self.name.chars().any(is_disallowed_invisible_char) {
+            return Err(
+                "Validation Error: Name contains disallowed bidi-control or zero-width characters"
+                    .into(),
+            );
+        }
 
         // Validate bio length
         if let Some(bio) = &self.bio {
@@ -212,6 +436,17 @@ impl Validatable for PubkyAppUser {
             for link in links {
                 link.validate(None)?;
             }
+
+            let total_bytes: usize = links
+                .iter()
+                .map(|link| link.title.len() + link.url.len())
+                .sum();
+            if total_bytes > VALIDATION_LIMITS.user_links_max_total_bytes {
+                return Err(format!(
+                    "Validation Error: Links exceed maximum combined size (max: {} bytes)",
+                    VALIDATION_LIMITS.user_links_max_total_bytes
+                ));
+            }
         }
 
         // Validate status length
@@ -221,6 +456,57 @@ impl Validatable for PubkyAppUser {
             }
         }
 
+        // Validate status expiry: an expiry may already be in the past when the
+        // profile is read, so only reject values that are not microseconds
+        if let Some(expires_at) = self.status_expires_at {
+            if expires_at < MIN_TIMESTAMP_MICROS {
+                return Err(
+                    "Validation Error: Status expiry must be in microseconds and after October 1st, 2024"
+                        .into(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_strict(&self, id: Option<&str>) -> Result<(), String> {
+        self.validate_strict_with_reserved_names(id, VALIDATION_LIMITS.user_reserved_names)
+    }
+}
+
+impl PubkyAppUser {
+    /// Like `validate_strict`, but rejects the given `reserved_names` instead
+    /// of the default `VALIDATION_LIMITS.user_reserved_names`. Names are
+    /// compared case-insensitively.
+    pub fn validate_strict_with_reserved_names(
+        &self,
+        id: Option<&str>,
+        reserved_names: &[&str],
+    ) -> Result<(), String> {
+        self.validate(id)?;
+
+        let name = self.name.trim().to_lowercase();
+        if reserved_names
+            .iter()
+            .any(|reserved| reserved.to_lowercase() == name)
+        {
+            return Err(format!("Validation Error: Name is reserved: {}", self.name));
+        }
+
+        // Reject duplicate link titles, compared case-insensitively
+        if let Some(links) = &self.links {
+            let mut titles = HashSet::new();
+            for link in links {
+                if !titles.insert(link.title.to_lowercase()) {
+                    return Err(format!(
+                        "Validation Error: Duplicate link title: {}",
+                        link.title
+                    ));
+                }
+            }
+        }
+
         Ok(())
     }
 }
@@ -234,6 +520,12 @@ impl PubkyAppUserLink {
     }
 }
 
+/// Limits for a single profile link.
+impl PubkyAppUserLink {
+    pub const MAX_TITLE_LENGTH: usize = VALIDATION_LIMITS.user_link_title_max_length;
+    pub const MAX_URL_LENGTH: usize = VALIDATION_LIMITS.user_link_url_max_length;
+}
+
 impl Validatable for PubkyAppUserLink {
     fn sanitize(self) -> Self {
         // Sanitize title: trim whitespace only
@@ -392,6 +684,16 @@ mod tests {
         assert!(result.unwrap_err().contains("Invalid name length"));
     }
 
+    #[test]
+    fn test_validate_empty_name() {
+        let user = PubkyAppUser::new("   ".to_string(), None, None, None, None);
+        assert_eq!(user.name, "");
+        assert_eq!(
+            user.validate(None).unwrap_err(),
+            "Validation Error: Name cannot be empty"
+        );
+    }
+
     #[test]
     fn test_try_from_valid() {
         let user_json = r#"
@@ -478,6 +780,10 @@ mod tests {
                 url: "  invalid_link_url  ".to_string(),
             }]),
             status: None,
+            status_expires_at: None,
+            schema_version: None,
+            #[cfg(feature = "unknown-fields")]
+            extra: Default::default(),
         };
 
         let sanitized = user.sanitize();
@@ -593,6 +899,59 @@ mod tests {
         assert!(result.unwrap_err().contains("Too many links"));
     }
 
+    #[test]
+    fn test_sanitize_dedupes_links_by_url() {
+        let links = vec![
+            PubkyAppUserLink {
+                title: "Blog".to_string(),
+                url: "https://example.com".to_string(),
+            },
+            PubkyAppUserLink {
+                title: "Other".to_string(),
+                url: "https://example.org".to_string(),
+            },
+            PubkyAppUserLink {
+                title: "Blog again".to_string(),
+                url: "  HTTPS://EXAMPLE.COM/ ".to_string(),
+            },
+        ];
+        let user = PubkyAppUser::new("Alice".to_string(), None, None, Some(links), None);
+
+        let links = user.links.unwrap();
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].title, "Blog");
+        assert_eq!(links[0].url, "https://example.com/");
+        assert_eq!(links[1].title, "Other");
+    }
+
+    #[test]
+    fn test_validate_links_total_bytes() {
+        let maxed_link = |i: usize| {
+            let url_prefix = format!("https://example.com/{i}");
+            PubkyAppUserLink {
+                title: format!("{i}").repeat(VALIDATION_LIMITS.user_link_title_max_length),
+                url: url_prefix.clone()
+                    + &"a".repeat(VALIDATION_LIMITS.user_link_url_max_length - url_prefix.len()),
+            }
+        };
+
+        // Every link is individually valid, but together they are too large
+        let links: Vec<_> = (0..VALIDATION_LIMITS.user_links_max_count)
+            .map(maxed_link)
+            .collect();
+        let user = PubkyAppUser::new("Alice".to_string(), None, None, Some(links), None);
+        for link in user.links.as_ref().unwrap() {
+            assert!(link.validate(None).is_ok());
+        }
+        let err = user.validate(None).unwrap_err();
+        assert!(err.contains("maximum combined size"), "{err}");
+
+        // A few maxed-out links stay within the budget
+        let links: Vec<_> = (0..3).map(maxed_link).collect();
+        let user = PubkyAppUser::new("Alice".to_string(), None, None, Some(links), None);
+        assert!(user.validate(None).is_ok());
+    }
+
     #[test]
     fn test_validate_link_length_errors() {
         // Test link title too long
@@ -730,4 +1089,259 @@ mod tests {
         let result = user.validate(None);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_schema_version_missing_treated_as_v1() {
+        let user_json = r#"{"name": "Alice"}"#;
+
+        // Deserialized without the field, the version is absent
+        let raw: PubkyAppUser = serde_json::from_str(user_json).unwrap();
+        assert_eq!(raw.schema_version, None);
+        assert!(raw.validate(None).is_ok());
+
+        // Sanitize stamps the current version
+        let user = <PubkyAppUser as Validatable>::try_from(user_json.as_bytes(), "").unwrap();
+        assert_eq!(user.schema_version, Some(CURRENT_SCHEMA_VERSION));
+    }
+
+    #[test]
+    fn test_schema_version_future_rejected() {
+        let user_json = format!(
+            r#"{{"name": "Alice", "schema_version": {}}}"#,
+            CURRENT_SCHEMA_VERSION + 1
+        );
+        let result = <PubkyAppUser as Validatable>::try_from(user_json.as_bytes(), "");
+        assert!(result.unwrap_err().contains("Unsupported schema version"));
+    }
+
+    #[test]
+    fn test_max_constants() {
+        assert_eq!(PubkyAppUser::MAX_BIO_LENGTH, 160);
+        assert_eq!(
+            PubkyAppUser::MAX_NAME_LENGTH,
+            VALIDATION_LIMITS.user_name_max_length
+        );
+        assert_eq!(
+            PubkyAppUserLink::MAX_TITLE_LENGTH,
+            VALIDATION_LIMITS.user_link_title_max_length
+        );
+    }
+
+    #[test]
+    fn test_validate_strict_duplicate_link_titles() {
+        let user = PubkyAppUser::new(
+            "Alice".to_string(),
+            None,
+            None,
+            Some(vec![
+                PubkyAppUserLink::new("Website".to_string(), "https://alice.dev".to_string()),
+                PubkyAppUserLink::new("website".to_string(), "https://alice.blog".to_string()),
+            ]),
+            None,
+        );
+
+        assert!(user.validate(None).is_ok());
+        assert_eq!(
+            user.validate_strict(None).unwrap_err(),
+            "Validation Error: Duplicate link title: website"
+        );
+    }
+
+    #[test]
+    fn test_validate_strict_reserved_names() {
+        let user_named = |name: &str| PubkyAppUser::new(name.to_string(), None, None, None, None);
+
+        let admin = user_named("Admin");
+        assert!(admin.validate(None).is_ok());
+        assert_eq!(
+            admin.validate_strict(None).unwrap_err(),
+            "Validation Error: Name is reserved: Admin"
+        );
+
+        let alice = user_named("Alice");
+        assert!(alice.validate_strict(None).is_ok());
+
+        // The placeholder name sanitize gives deleted profiles is not reserved
+        let deleted = user_named("[DELETED]");
+        assert_eq!(deleted.name, "anonymous");
+        assert!(deleted.validate_strict(None).is_ok());
+        assert!(PubkyAppUser::default().validate_strict(None).is_ok());
+
+        // The list is configurable
+        assert!(admin.validate_strict_with_reserved_names(None, &[]).is_ok());
+        assert!(alice
+            .validate_strict_with_reserved_names(None, &["alice"])
+            .is_err());
+    }
+
+    #[test]
+    fn test_image_dimensions() {
+        let with_image = |image: &str| {
+            PubkyAppUser::new(
+                "Alice".to_string(),
+                None,
+                Some(image.to_string()),
+                None,
+                None,
+            )
+        };
+
+        let user = with_image("https://example.com/avatar.png?w=200&h=100");
+        assert_eq!(user.image_dimensions(), Some((200, 100)));
+        assert!(user.validate(None).is_ok());
+
+        assert_eq!(
+            with_image("https://example.com/avatar.png").image_dimensions(),
+            None
+        );
+        assert_eq!(
+            with_image("https://example.com/avatar.png?w=200").image_dimensions(),
+            None
+        );
+        assert_eq!(
+            with_image("https://example.com/avatar.png?w=big&h=100").image_dimensions(),
+            None
+        );
+        assert_eq!(PubkyAppUser::default().image_dimensions(), None);
+    }
+
+    #[test]
+    fn test_name_bidi_override_stripped_and_rejected() {
+        let user = PubkyAppUser::new("Alice\u{202E}\u{200B}".to_string(), None, None, None, None);
+        assert_eq!(user.name, "Alice");
+        assert!(user.validate(None).is_ok());
+
+        let user = PubkyAppUser {
+            name: "Ali\u{202E}ce".to_string(),
+            ..PubkyAppUser::default()
+        };
+        assert!(user
+            .validate(None)
+            .unwrap_err()
+            .contains("bidi-control or zero-width"));
+    }
+
+    #[test]
+    fn test_status_expiry() {
+        let now = 1_733_011_200_000_000;
+        let with_expiry = |expires_at: Option<i64>| PubkyAppUser {
+            status: Some("In a meeting".to_string()),
+            status_expires_at: expires_at,
+            ..PubkyAppUser::default()
+        };
+
+        let expired = with_expiry(Some(now - 1_000_000));
+        assert!(expired.status_is_expired(now));
+        assert!(expired.validate(None).is_ok());
+
+        let live = with_expiry(Some(now + 3_600_000_000));
+        assert!(!live.status_is_expired(now));
+        assert!(!with_expiry(None).status_is_expired(now));
+
+        // Seconds instead of microseconds are rejected
+        assert!(with_expiry(Some(1_733_011_200)).validate(None).is_err());
+
+        // An expiry without a status is dropped on sanitize
+        let no_status = PubkyAppUser {
+            status: None,
+            ..live.clone()
+        }
+        .sanitize();
+        assert_eq!(no_status.status_expires_at, None);
+
+        // Missing in JSON and omitted when unset
+        let user: PubkyAppUser = serde_json::from_str(r#"{"name": "Alice"}"#).unwrap();
+        assert_eq!(user.status_expires_at, None);
+        assert!(!serde_json::to_string(&user)
+            .unwrap()
+            .contains("status_expires_at"));
+    }
+
+    #[test]
+    fn test_bio_plaintext() {
+        let with_bio = |bio: &str| {
+            PubkyAppUser::new("Alice".to_string(), Some(bio.to_string()), None, None, None)
+        };
+
+        assert_eq!(
+            with_bio("Building [Pubky](https://pubky.app) in **Rust**")
+                .bio_plaintext()
+                .as_deref(),
+            Some("Building Pubky in Rust")
+        );
+        assert_eq!(
+            with_bio("![me](https://example.com/me.png) _likes_ `code` and ~~bugs~~")
+                .bio_plaintext()
+                .as_deref(),
+            Some("me likes code and bugs")
+        );
+        assert_eq!(
+            with_bio("Plain bio about snake_case, 2*3 and ~5 [brackets]")
+                .bio_plaintext()
+                .as_deref(),
+            Some("Plain bio about snake_case, 2*3 and ~5 [brackets]")
+        );
+        assert_eq!(PubkyAppUser::default().bio_plaintext(), None);
+    }
+
+    #[test]
+    fn test_image_url() {
+        let user = PubkyAppUser::new(
+            "Alice".to_string(),
+            None,
+            Some(" https://example.com/avatar.png ".to_string()),
+            None,
+            None,
+        );
+        let url = user.image_url().unwrap();
+        assert_eq!(url.host_str(), Some("example.com"));
+        assert_eq!(url.path(), "/avatar.png");
+
+        assert_eq!(PubkyAppUser::default().image_url(), None);
+    }
+
+    #[test]
+    fn test_merge_overwrites_bio_and_preserves_links() {
+        let links = vec![PubkyAppUserLink {
+            title: "blog".to_string(),
+            url: "https://example.com".to_string(),
+        }];
+        let mut user = PubkyAppUser::new(
+            "Alice".to_string(),
+            Some("Old bio".to_string()),
+            None,
+            Some(links),
+            Some("online".to_string()),
+        );
+
+        user.merge(PubkyAppUserPatch {
+            bio: Some(Some("  New bio  ".to_string())),
+            status: Some(None),
+            ..Default::default()
+        });
+
+        assert_eq!(user.name, "Alice");
+        assert_eq!(user.bio.as_deref(), Some("New bio"));
+        assert_eq!(user.status, None);
+        let links = user.links.as_ref().unwrap();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].url, "https://example.com/");
+        assert_eq!(user.schema_version, Some(CURRENT_SCHEMA_VERSION));
+        assert!(user.validate(None).is_ok());
+    }
+
+    #[test]
+    fn test_merge_empty_patch_is_noop() {
+        let mut user = PubkyAppUser::new(
+            "Alice".to_string(),
+            Some("bio".to_string()),
+            Some("https://example.com/image.png".to_string()),
+            None,
+            None,
+        );
+        user.merge(PubkyAppUserPatch::default());
+        assert_eq!(user.name, "Alice");
+        assert_eq!(user.bio.as_deref(), Some("bio"));
+        assert_eq!(user.image.as_deref(), Some("https://example.com/image.png"));
+    }
 }
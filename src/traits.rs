@@ -1,13 +1,16 @@
-use crate::common::{timestamp, validate_crockford_id};
+use crate::common::{
+    blake3_crockford_id, timestamp_micros, validate_crockford_id, MAX_FUTURE_MICROS,
+    MIN_TIMESTAMP_MICROS,
+};
+use crate::ResourceKind;
 use base32::{encode, Alphabet};
-use blake3::Hasher;
 use serde::de::DeserializeOwned;
 
 pub trait TimestampId {
     /// Creates a unique identifier based on the current timestamp.
     fn create_id(&self) -> String {
         // Get current time in microseconds since UNIX epoch
-        let now = timestamp();
+        let now = timestamp_micros();
 
         // Convert to big-endian bytes
         let bytes = now.to_be_bytes();
@@ -23,26 +26,20 @@ pub trait TimestampId {
         let decoded_bytes = validate_crockford_id(id)?;
 
         // Convert the decoded bytes to a timestamp in microseconds
-        let timestamp_micros = i64::from_be_bytes(decoded_bytes);
-
-        // Get current time in microseconds
-        let now_micros = timestamp();
-
-        // Define October 1st, 2024, in microseconds since UNIX epoch
-        let oct_first_2024_micros = 1727740800000000; // Timestamp for 2024-10-01 00:00:00 UTC
+        let id_micros = i64::from_be_bytes(decoded_bytes);
 
         // Allowable future duration (2 hours) in microseconds
-        let max_future_micros = now_micros + 2 * 60 * 60 * 1_000_000;
+        let max_future_micros = timestamp_micros() + MAX_FUTURE_MICROS;
 
         // Validate that the ID's timestamp is after October 1st, 2024
-        if timestamp_micros < oct_first_2024_micros {
+        if id_micros < MIN_TIMESTAMP_MICROS {
             return Err(
                 "Validation Error: Invalid ID, timestamp must be after October 1st, 2024".into(),
             );
         }
 
         // Validate that the ID's timestamp is not more than 2 hours in the future
-        if timestamp_micros > max_future_micros {
+        if id_micros > max_future_micros {
             return Err("Validation Error: Invalid ID, timestamp is too far in the future".into());
         }
 
@@ -66,20 +63,11 @@ pub trait HashId {
     ///
     /// # Returns
     /// - A `String` representing the Crockford-encoded tag ID derived from the `blake3` hash of the concatenated `uri` and `label`.
+    ///
+    /// The id is hashed on every call; keep the returned value (or the `Meta` id)
+    /// rather than calling this repeatedly on the same instance.
     fn create_id(&self) -> String {
-        let data = self.get_id_data();
-
-        // Create a Blake3 hash of the input data
-        let mut hasher = Hasher::new();
-        hasher.update(data.as_bytes());
-        let blake3_hash = hasher.finalize();
-
-        // Get the first half of the hash bytes
-        let half_hash_length = blake3_hash.as_bytes().len() / 2;
-        let half_hash = &blake3_hash.as_bytes()[..half_hash_length];
-
-        // Encode the first half of the hash in Base32 using the Z-base32 alphabet
-        encode(Alphabet::Crockford, half_hash)
+        blake3_crockford_id(self.get_id_data().as_bytes())
     }
 
     /// Validates that the provided ID matches the generated ID.
@@ -100,13 +88,58 @@ pub trait Validatable: Sized + DeserializeOwned {
         Ok(instance)
     }
 
+    /// Like `try_from`, but deserializes straight from a reader, so large
+    /// objects read from a stream don't need to be buffered first.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn try_from_reader<R: std::io::Read>(reader: R, id: &str) -> Result<Self, String> {
+        let instance: Self = serde_json::from_reader(reader).map_err(|e| e.to_string())?;
+        let instance = instance.sanitize();
+        instance.validate(Some(id))?;
+        Ok(instance)
+    }
+
+    /// Runs the same pipeline as `try_from` but only reports whether the blob
+    /// is valid. Deserialization errors are not formatted, which keeps the
+    /// common reject path for malformed blobs cheap.
+    fn is_valid(blob: &[u8], id: &str) -> bool {
+        serde_json::from_slice::<Self>(blob)
+            .is_ok_and(|instance| instance.sanitize().validate(Some(id)).is_ok())
+    }
+
+    /// Like `try_from`, but validation failures do not discard the object.
+    ///
+    /// The blob is deserialized and sanitized, then validated; any validation
+    /// error is returned alongside the object instead of failing. Only
+    /// deserialization errors are hard failures. Intended for debugging and
+    /// forensic tools inspecting malformed homeserver data.
+    fn try_from_lenient(blob: &[u8], id: &str) -> Result<(Self, Vec<String>), String> {
+        let instance: Self = serde_json::from_slice(blob).map_err(|e| e.to_string())?;
+        let instance = instance.sanitize();
+        let notes = instance.validate(Some(id)).err().into_iter().collect();
+        Ok((instance, notes))
+    }
+
     fn validate(&self, id: Option<&str>) -> Result<(), String>;
 
+    /// Validates the object, additionally applying strict-mode rules.
+    ///
+    /// Strict rules are opt-in checks that existing clients may legitimately
+    /// violate, so they are kept out of `validate`. Defaults to `validate`.
+    fn validate_strict(&self, id: Option<&str>) -> Result<(), String> {
+        self.validate(id)
+    }
+
     fn sanitize(self) -> Self {
         self
     }
 }
 
+/// Maps a model type to the kind of resource it is stored as, so generic code
+/// can dispatch on `T::KIND` instead of matching on `Resource`.
+pub trait ResourceModel {
+    const KIND: ResourceKind;
+}
+
 pub trait HasPath {
     const PATH_SEGMENT: &'static str;
     fn create_path() -> String;
@@ -117,6 +150,32 @@ pub trait HasIdPath {
     fn create_path(id: &str) -> String;
 }
 
+/// Bundles what a client needs to write a model to the homeserver: its path
+/// and its serialized body.
+pub trait PutPayload: Validatable + serde::Serialize + Clone {
+    /// Returns the homeserver path of the object stored under `id`.
+    ///
+    /// Content-addressed models derive the id when it is `None`; models keyed
+    /// by a timestamp or a user id require it.
+    fn put_path(&self, id: Option<&str>) -> Result<String, String>;
+
+    /// Sanitizes and validates the object, then returns its homeserver path
+    /// and JSON body.
+    fn to_put_payload(&self, id: Option<&str>) -> Result<(String, Vec<u8>), String> {
+        let object = self.clone().sanitize();
+        object.validate(id)?;
+        let path = object.put_path(id)?;
+        let body =
+            serde_json::to_vec(&object).map_err(|e| format!("JSON serialization error: {}", e))?;
+        Ok((path, body))
+    }
+}
+
+/// Returns `id`, or an error naming the model when it is missing.
+pub(crate) fn required_id<'a>(id: Option<&'a str>, model: &str) -> Result<&'a str, String> {
+    id.ok_or_else(|| format!("Validation Error: An id is required to build the {model} path"))
+}
+
 #[cfg(target_arch = "wasm32")]
 use serde::Serialize;
 #[cfg(target_arch = "wasm32")]
@@ -139,3 +198,147 @@ pub trait Json: Serialize + DeserializeOwned + Validatable {
         Ok(object)
     }
 }
+
+/// Native counterpart of the wasm `Json` trait, converting models from and to
+/// JSON strings. Implemented for every `Validatable` model.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait JsonNative: serde::Serialize + DeserializeOwned + Validatable {
+    /// Parses a JSON string, then sanitizes and validates the object.
+    fn from_json_str(json: &str) -> Result<Self, String> {
+        let object: Self =
+            serde_json::from_str(json).map_err(|e| format!("Error parsing JSON: {}", e))?;
+        let object = object.sanitize();
+        object.validate(None)?;
+        Ok(object)
+    }
+
+    fn to_json_string(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| format!("JSON serialization error: {}", e))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: serde::Serialize + DeserializeOwned + Validatable> JsonNative for T {}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::{PubkyAppBlob, PubkyAppPost, PubkyAppPostKind, PubkyAppUser};
+
+    #[test]
+    fn test_json_native_round_trip() {
+        let post = PubkyAppPost::new(
+            "Hello".to_string(),
+            PubkyAppPostKind::Short,
+            None,
+            None,
+            None,
+        );
+        let json = post.to_json_string().unwrap();
+        let parsed = PubkyAppPost::from_json_str(&json).unwrap();
+        assert_eq!(parsed.content, "Hello");
+        assert_eq!(parsed.kind, PubkyAppPostKind::Short);
+    }
+
+    #[test]
+    fn test_json_native_sanitizes_and_validates() {
+        let user = PubkyAppUser::from_json_str(r#"{"name": "  Alice  "}"#).unwrap();
+        assert_eq!(user.name, "Alice");
+
+        assert!(PubkyAppUser::from_json_str(r#"{"name": "Al"}"#).is_err());
+        assert!(PubkyAppUser::from_json_str("not json").is_err());
+    }
+
+    #[test]
+    fn test_try_from_reader() {
+        use std::io::Cursor;
+
+        let post = PubkyAppPost::new(
+            "  Hello  ".to_string(),
+            PubkyAppPostKind::Short,
+            None,
+            None,
+            None,
+        );
+        let id = post.create_id();
+        let reader = Cursor::new(serde_json::to_vec(&post).unwrap());
+        let parsed = PubkyAppPost::try_from_reader(reader, &id).unwrap();
+        assert_eq!(parsed.content, "Hello");
+
+        let invalid = Cursor::new(br#"{"content": "", "kind": "short"}"#.to_vec());
+        assert!(PubkyAppPost::try_from_reader(invalid, &id).is_err());
+        let malformed = Cursor::new(b"not json".to_vec());
+        assert!(PubkyAppPost::try_from_reader(malformed, &id).is_err());
+
+        // Blobs are raw bytes, not JSON
+        let blob = PubkyAppBlob::new(vec![1, 2, 3]);
+        let blob_id = blob.create_id();
+        let parsed = PubkyAppBlob::try_from_reader(Cursor::new(vec![1, 2, 3]), &blob_id).unwrap();
+        assert_eq!(parsed.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_to_put_payload() {
+        let user = PubkyAppUser::new("  Alice  ".to_string(), None, None, None, None);
+        let (path, body) = user.to_put_payload(None).unwrap();
+        assert_eq!(path, PubkyAppUser::create_path());
+        let written: PubkyAppUser = serde_json::from_slice(&body).unwrap();
+        assert_eq!(written.name, "Alice");
+
+        let post = PubkyAppPost::new(
+            "Hello".to_string(),
+            PubkyAppPostKind::Short,
+            None,
+            None,
+            None,
+        );
+        let id = post.create_id();
+        let (path, body) = post.to_put_payload(Some(&id)).unwrap();
+        assert_eq!(path, PubkyAppPost::create_path(&id));
+        let written = <PubkyAppPost as Validatable>::try_from(&body, &id).unwrap();
+        assert_eq!(written.content, "Hello");
+
+        // Posts are keyed by a timestamp id that can't be derived
+        assert!(post.to_put_payload(None).is_err());
+        // Invalid objects are rejected before serialization
+        let empty = PubkyAppPost::empty_short();
+        assert!(empty.to_put_payload(Some(&id)).is_err());
+
+        // Blob bodies are the raw bytes
+        let blob = PubkyAppBlob::new(vec![1, 2, 3]);
+        let (path, body) = blob.to_put_payload(None).unwrap();
+        assert_eq!(path, PubkyAppBlob::create_path(&blob.create_id()));
+        assert_eq!(body, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_is_valid_matches_try_from() {
+        let post = PubkyAppPost::new(
+            "Hello".to_string(),
+            PubkyAppPostKind::Short,
+            None,
+            None,
+            None,
+        );
+        let id = post.create_id();
+        let cases: [&[u8]; 4] = [
+            br#"{"content": "Hello", "kind": "short"}"#,
+            br#"{"content": "", "kind": "short"}"#,
+            br#"{"content": "Hello", "kind": "invalid"}"#,
+            b"not json",
+        ];
+        for blob in cases {
+            assert_eq!(
+                PubkyAppPost::is_valid(blob, &id),
+                <PubkyAppPost as Validatable>::try_from(blob, &id).is_ok()
+            );
+        }
+        assert!(PubkyAppPost::is_valid(cases[0], &id));
+        assert!(!PubkyAppPost::is_valid(cases[0], "0000000000000"));
+
+        let data = b"raw bytes";
+        let blob_id = PubkyAppBlob::new(data.to_vec()).create_id();
+        assert!(PubkyAppBlob::is_valid(data, &blob_id));
+        assert!(!PubkyAppBlob::is_valid(b"", &blob_id));
+    }
+}
@@ -22,7 +22,12 @@ pub struct PubkyId {
 #[cfg(feature = "openapi")]
 impl PartialSchema for PubkyId {
     fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
-        String::schema()
+        utoipa::openapi::ObjectBuilder::new()
+            .schema_type(utoipa::openapi::schema::Type::String)
+            .min_length(Some(52))
+            .max_length(Some(52))
+            .description(Some("z-base-32 encoded public key"))
+            .into()
     }
 }
 
@@ -68,10 +73,32 @@ impl PubkyId {
     pub fn to_uri(&self) -> ParsedUri {
         ParsedUri {
             user_id: self.clone(),
-            resource: Resource::User,
+            resource: Resource::User(None),
         }
     }
 
+    /// Decodes the z-base-32 id into the raw 32-byte public key.
+    pub fn to_public_key_bytes(&self) -> Result<[u8; 32], String> {
+        let bytes = decode(Alphabet::Z, &self.z32)
+            .ok_or("Validation Error: invalid public key encoding")?;
+        bytes.try_into().map_err(|bytes: Vec<u8>| {
+            format!(
+                "Validation Error: public key must be 32 bytes, got {}",
+                bytes.len()
+            )
+        })
+    }
+
+    /// Seed for the identicon clients render when a user has no `image`.
+    ///
+    /// This is the decoded 32-byte public key, so every app feeding it into
+    /// its identicon generator draws the same avatar for the same user.
+    pub fn identicon_seed(&self) -> [u8; 32] {
+        // A valid 52-char z-base-32 id always decodes to 32 bytes
+        self.to_public_key_bytes()
+            .expect("PubkyId holds a validated public key")
+    }
+
     /// Returns the cached public key.
     ///
     /// This is infallible on native targets because the key was validated
@@ -119,6 +146,26 @@ impl AsRef<str> for PubkyId {
     }
 }
 
+/// The bytes of the z-base-32 string, not the decoded public key; use
+/// `to_public_key_bytes` for the key itself.
+impl AsRef<[u8]> for PubkyId {
+    fn as_ref(&self) -> &[u8] {
+        self.z32.as_bytes()
+    }
+}
+
+impl From<&PubkyId> for String {
+    fn from(id: &PubkyId) -> Self {
+        id.z32.clone()
+    }
+}
+
+impl From<PubkyId> for String {
+    fn from(id: PubkyId) -> Self {
+        id.z32
+    }
+}
+
 impl Serialize for PubkyId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -145,12 +192,45 @@ mod tests {
     #[cfg(not(target_arch = "wasm32"))]
     use pubky::Keypair;
 
+    #[test]
+    fn test_to_public_key_bytes() {
+        let id = PubkyId::try_from("operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo").unwrap();
+        let bytes = id.to_public_key_bytes().unwrap();
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(base32::encode(Alphabet::Z, &bytes), id.to_string());
+    }
+
+    #[test]
+    fn test_identicon_seed_is_deterministic() {
+        let z32 = "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo";
+        let seed = PubkyId::try_from(z32).unwrap().identicon_seed();
+        assert_eq!(seed, PubkyId::try_from(z32).unwrap().identicon_seed());
+        assert_eq!(base32::encode(Alphabet::Z, &seed), z32);
+
+        let other = PubkyId::try_from("pxnu33x7jtpx9ar1ytsi4yxbp6a5o36gwhffs8zoxmbuptici1jy")
+            .unwrap()
+            .identicon_seed();
+        assert_ne!(seed, other);
+    }
+
+    #[test]
+    fn test_string_and_bytes_conversions() {
+        let z32 = "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo";
+        let id = PubkyId::try_from(z32).unwrap();
+
+        assert_eq!(String::from(&id), z32);
+        let bytes: &[u8] = id.as_ref();
+        assert_eq!(bytes, z32.as_bytes());
+        let owned: String = id.into();
+        assert_eq!(owned, z32);
+    }
+
     #[test]
     fn test_try_from_valid() {
         let valid_key = "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo";
         let result = PubkyId::try_from(valid_key);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap().as_ref(), valid_key);
+        assert_eq!(&*result.unwrap(), valid_key);
     }
 
     #[test]
@@ -216,7 +296,7 @@ mod tests {
         let pubky_id = PubkyId::from(public_key);
 
         // The inner string should be 52 characters (valid z32 public key)
-        assert_eq!(pubky_id.as_ref().len(), 52);
+        assert_eq!(pubky_id.len(), 52);
 
         // Should also work with try_from validation
         let result = PubkyId::try_from(pubky_id.as_ref());
@@ -231,7 +311,7 @@ mod tests {
         let pubky_id = PubkyId::from(keypair);
 
         // The inner string should be 52 characters (valid z32 public key)
-        assert_eq!(pubky_id.as_ref().len(), 52);
+        assert_eq!(pubky_id.len(), 52);
 
         // Should also work with try_from validation
         let result = PubkyId::try_from(pubky_id.as_ref());
@@ -286,7 +366,7 @@ mod tests {
         let json = format!("\"{}\"", valid_key);
         let pubky_id: PubkyId = serde_json::from_str(&json).unwrap();
 
-        assert_eq!(pubky_id.as_ref(), valid_key);
+        assert_eq!(&*pubky_id, valid_key);
     }
 
     #[test]
@@ -296,7 +376,7 @@ mod tests {
         let json_str = format!("\"{}\"", valid_key);
         let pubky_id: PubkyId = serde_json::from_slice(json_str.as_bytes()).unwrap();
 
-        assert_eq!(pubky_id.as_ref(), valid_key);
+        assert_eq!(&*pubky_id, valid_key);
     }
 
     #[test]
@@ -1,17 +1,23 @@
 use crate::{
     traits::{HasIdPath, HasPath},
-    PubkyAppBlob, PubkyAppBookmark, PubkyAppFeed, PubkyAppFile, PubkyAppFollow, PubkyAppLastRead,
-    PubkyAppMute, PubkyAppPost, PubkyAppTag, PubkyAppUser, PubkyId, APP_PATH, PROTOCOL,
-    PUBLIC_PATH,
+    user_uri_builder, PubkyAppBlob, PubkyAppBookmark, PubkyAppFeed, PubkyAppFile, PubkyAppFollow,
+    PubkyAppLastRead, PubkyAppMute, PubkyAppPost, PubkyAppTag, PubkyAppUser, PubkyId, APP_PATH,
+    PROTOCOL, PUBLIC_PATH,
 };
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 use url::Url;
 
-#[derive(Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub enum Resource {
-    User,
+    /// The user profile: `profile.json`, or `profile.<locale>.json` for a
+    /// locale-specific profile, whose locale is carried here.
+    User(Option<String>),
     Post(String),
     Follow(PubkyId),
     Mute(PubkyId),
@@ -21,7 +27,35 @@ pub enum Resource {
     Blob(String),
     Feed(String),
     LastRead,
-    #[default]
+    /// The app root itself, `pubky://<user_id>/pub/pubky.app/`.
+    Root,
+    /// A resource this version of the spec does not recognize, with its
+    /// resource-type segment (e.g. `notifications`) when the path has one.
+    Unknown(Option<String>),
+}
+
+impl Default for Resource {
+    fn default() -> Self {
+        Resource::Unknown(None)
+    }
+}
+
+/// The kind of a [Resource], without its identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum ResourceKind {
+    User,
+    Post,
+    Follow,
+    Mute,
+    Bookmark,
+    Tag,
+    File,
+    Blob,
+    Feed,
+    LastRead,
+    Root,
     Unknown,
 }
 
@@ -30,7 +64,7 @@ impl fmt::Display for Resource {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Use the associated constant for each resource type, trimming any trailing '/'
         let name = match self {
-            Resource::User => PubkyAppUser::PATH_SEGMENT.trim_end_matches('/'),
+            Resource::User(_) => PubkyAppUser::PATH_SEGMENT.trim_end_matches('/'),
             Resource::LastRead => PubkyAppLastRead::PATH_SEGMENT.trim_end_matches('/'),
             Resource::Post(_) => PubkyAppPost::PATH_SEGMENT.trim_end_matches('/'),
             Resource::Follow(_) => PubkyAppFollow::PATH_SEGMENT.trim_end_matches('/'),
@@ -40,13 +74,33 @@ impl fmt::Display for Resource {
             Resource::File(_) => PubkyAppFile::PATH_SEGMENT.trim_end_matches('/'),
             Resource::Blob(_) => PubkyAppBlob::PATH_SEGMENT.trim_end_matches('/'),
             Resource::Feed(_) => PubkyAppFeed::PATH_SEGMENT.trim_end_matches('/'),
-            Resource::Unknown => "unknown",
+            Resource::Root => "root",
+            Resource::Unknown(Some(segment)) => return write!(f, "unknown ({})", segment),
+            Resource::Unknown(None) => "unknown",
         };
         write!(f, "{}", name)
     }
 }
 
 impl Resource {
+    /// Returns the kind of this resource.
+    pub fn kind(&self) -> ResourceKind {
+        match self {
+            Resource::User(_) => ResourceKind::User,
+            Resource::Post(_) => ResourceKind::Post,
+            Resource::Follow(_) => ResourceKind::Follow,
+            Resource::Mute(_) => ResourceKind::Mute,
+            Resource::Bookmark(_) => ResourceKind::Bookmark,
+            Resource::Tag(_) => ResourceKind::Tag,
+            Resource::File(_) => ResourceKind::File,
+            Resource::Blob(_) => ResourceKind::Blob,
+            Resource::Feed(_) => ResourceKind::Feed,
+            Resource::LastRead => ResourceKind::LastRead,
+            Resource::Root => ResourceKind::Root,
+            Resource::Unknown(_) => ResourceKind::Unknown,
+        }
+    }
+
     /// Returns the identifier as a `Some(String)` if the resource variant holds one,
     /// or `None` if there is no identifier.
     pub fn id(&self) -> Option<String> {
@@ -60,12 +114,13 @@ impl Resource {
             Resource::Blob(id) => Some(id.clone()),
             Resource::Feed(id) => Some(id.clone()),
             // The following variants do not carry an id.
-            Resource::User | Resource::LastRead | Resource::Unknown => None,
+            Resource::User(_) | Resource::LastRead | Resource::Root | Resource::Unknown(_) => None,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct ParsedUri {
     pub user_id: PubkyId,
     pub resource: Resource,
@@ -73,12 +128,31 @@ pub struct ParsedUri {
 
 impl ParsedUri {
     /// Converts the [ParsedUri] back into its URI string representation.
-    /// Returns an error if the resource is Unknown.
+    /// Returns an error if the resource is Unknown. The app root maps back to
+    /// `pubky://<user_id>/pub/pubky.app/`.
     pub fn try_to_uri_str(&self) -> Result<String, String> {
+        let path = self
+            .to_path()
+            .map_err(|_| "Cannot convert Unknown resource to URI".to_string())?;
+        Ok([PROTOCOL, self.user_id.as_ref(), &path].concat())
+    }
+
+    /// Returns only the `/pub/pubky.app/...` path of the resource, without the
+    /// `pubky://<user_id>` prefix, e.g. for a client already scoped to the user.
+    /// Returns an error if the resource is Unknown.
+    pub fn to_path(&self) -> Result<String, String> {
         use crate::traits::{HasIdPath, HasPath};
 
         let path = match &self.resource {
-            Resource::User => PubkyAppUser::create_path(),
+            Resource::User(None) => PubkyAppUser::create_path(),
+            Resource::User(Some(locale)) => [
+                PUBLIC_PATH,
+                APP_PATH,
+                PROFILE_PREFIX,
+                locale,
+                PROFILE_SUFFIX,
+            ]
+            .concat(),
             Resource::LastRead => PubkyAppLastRead::create_path(),
             Resource::Post(id) => PubkyAppPost::create_path(id),
             Resource::Follow(id) => PubkyAppFollow::create_path(id.as_ref()),
@@ -88,10 +162,18 @@ impl ParsedUri {
             Resource::File(id) => PubkyAppFile::create_path(id),
             Resource::Blob(id) => PubkyAppBlob::create_path(id),
             Resource::Feed(id) => PubkyAppFeed::create_path(id),
-            Resource::Unknown => return Err("Cannot convert Unknown resource to URI".to_string()),
+            Resource::Root => [PUBLIC_PATH, APP_PATH].concat(),
+            Resource::Unknown(_) => {
+                return Err("Cannot convert Unknown resource to path".to_string())
+            }
         };
+        Ok(path)
+    }
 
-        Ok([PROTOCOL, self.user_id.as_ref(), &path].concat())
+    /// Returns the profile URI of the resource's author,
+    /// `pubky://<user_id>/pub/pubky.app/profile.json`, for any resource kind.
+    pub fn author_profile_uri(&self) -> String {
+        user_uri_builder(self.user_id.to_string())
     }
 }
 
@@ -138,18 +220,32 @@ impl TryFrom<&str> for ParsedUri {
             ));
         }
 
-        // 4. Determine the resource from the remaining segments.
+        // 4. Determine the resource from the remaining segments. Unrecognized
+        // paths keep their resource-type segment, if any.
+        let unknown = || {
+            Resource::Unknown(
+                segments
+                    .get(2)
+                    .filter(|segment| !segment.is_empty())
+                    .map(|segment| segment.to_string()),
+            )
+        };
         let resource = match segments[2..] {
-            // No extra segments.
-            [] => Resource::Unknown,
+            // No extra segments (or only the trailing slash): the app root.
+            [] | [""] => Resource::Root,
             // A single segment: must exactly match an identifier-less route.
             [segment] => match segment {
-                PubkyAppUser::PATH_SEGMENT => Resource::User,
+                PubkyAppUser::PATH_SEGMENT => Resource::User(None),
                 PubkyAppLastRead::PATH_SEGMENT => Resource::LastRead,
-                _ => Resource::Unknown,
+                _ => match profile_locale(segment) {
+                    Some(locale) => Resource::User(Some(locale.to_string())),
+                    None => unknown(),
+                },
             },
-            // Two or more segments and the id is not empty.
-            [res_type, id, ..] if !id.is_empty() => {
+            // A resource type and a non-empty id, optionally followed by a
+            // single trailing slash. An empty id (`posts/`, `posts//<id>`) or
+            // extra segments (`posts/<id>/extra`) never yield a valid id.
+            [res_type, id] | [res_type, id, ""] if !id.is_empty() => {
                 let resource_type = format!("{}/", res_type);
                 match resource_type.as_str() {
                     PubkyAppPost::PATH_SEGMENT => Resource::Post(id.to_string()),
@@ -160,17 +256,36 @@ impl TryFrom<&str> for ParsedUri {
                     PubkyAppFile::PATH_SEGMENT => Resource::File(id.to_string()),
                     PubkyAppBlob::PATH_SEGMENT => Resource::Blob(id.to_string()),
                     PubkyAppFeed::PATH_SEGMENT => Resource::Feed(id.to_string()),
-                    _ => Resource::Unknown,
+                    _ => unknown(),
                 }
             }
-            // If the identifier is empty or doesn't match the expected pattern.
-            _ => Resource::Unknown,
+            // If the identifier is empty or the path has unexpected segments.
+            _ => unknown(),
         };
 
         Ok(ParsedUri { user_id, resource })
     }
 }
 
+/// Prefix and suffix of a locale-specific profile, `profile.<locale>.json`.
+const PROFILE_PREFIX: &str = "profile.";
+const PROFILE_SUFFIX: &str = ".json";
+
+/// Returns the locale of a `profile.<locale>.json` segment. The locale must be
+/// a BCP 47-like tag: ASCII letters, digits and `-`, such as `pt` or `pt-BR`.
+fn profile_locale(segment: &str) -> Option<&str> {
+    let locale = segment
+        .strip_prefix(PROFILE_PREFIX)?
+        .strip_suffix(PROFILE_SUFFIX)?;
+    let well_formed = (2..=35).contains(&locale.len())
+        && locale
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && !locale.starts_with('-')
+        && !locale.ends_with('-');
+    well_formed.then_some(locale)
+}
+
 impl TryFrom<String> for ParsedUri {
     type Error = String;
 
@@ -194,7 +309,7 @@ mod tests {
             ParsedUri::try_from(uri).expect("empty bookmark id should parse to Unknown");
         assert_eq!(
             parsed_uri.resource,
-            Resource::Unknown,
+            Resource::Unknown(Some("bookmarks".into())),
             "The provided URI has bookmark_id"
         );
     }
@@ -216,7 +331,7 @@ mod tests {
         let parsed_uri = ParsedUri::try_from(uri).expect("user uri should parse");
         assert_eq!(
             parsed_uri.resource,
-            Resource::User,
+            Resource::User(None),
             "The provided URI is not user resource type"
         );
     }
@@ -231,12 +346,12 @@ mod tests {
         let uri = user_uri_builder(USER_ID.into());
         let parsed = ParsedUri::try_from(uri).expect("Failed to parse valid user URI");
         assert_eq!(parsed.user_id, user_id);
-        assert_eq!(parsed.resource, Resource::User);
+        assert_eq!(parsed.resource, Resource::User(None));
 
         // Repeat same checks for ParsedUri derived directly from PubkyId
         let parsed_uri_from_pubky_id = user_id.to_uri();
         assert_eq!(parsed_uri_from_pubky_id.user_id, user_id);
-        assert_eq!(parsed_uri_from_pubky_id.resource, Resource::User);
+        assert_eq!(parsed_uri_from_pubky_id.resource, Resource::User(None));
     }
 
     #[test]
@@ -323,12 +438,63 @@ mod tests {
     #[test]
     fn test_no_resource_segments() {
         // When there are no segments beyond the public and app paths,
-        // the resource should be Unknown.
+        // the resource is the app root.
         let uri = format!("pubky://{USER_ID}/pub/pubky.app/");
         let parsed =
             ParsedUri::try_from(uri).expect("Failed to parse URI with no resource segments");
         assert_eq!(parsed.user_id, PubkyId::try_from(USER_ID).unwrap());
-        assert_eq!(parsed.resource, Resource::Unknown);
+        assert_eq!(parsed.resource, Resource::Root);
+    }
+
+    #[test]
+    fn test_root_is_distinct_from_unknown() {
+        for uri in [
+            format!("pubky://{USER_ID}/pub/pubky.app/"),
+            format!("pubky://{USER_ID}/pub/pubky.app"),
+        ] {
+            let parsed = ParsedUri::try_from(uri.as_str()).unwrap();
+            assert_eq!(parsed.resource, Resource::Root, "{uri}");
+            assert_eq!(parsed.resource.kind(), ResourceKind::Root);
+            assert_eq!(parsed.resource.id(), None);
+            assert_eq!(parsed.resource.to_string(), "root");
+        }
+
+        let unknown =
+            ParsedUri::try_from(format!("pubky://{USER_ID}/pub/pubky.app/unknown/xyz")).unwrap();
+        assert_eq!(unknown.resource, Resource::Unknown(Some("unknown".into())));
+        assert_ne!(unknown.resource.kind(), ResourceKind::Root);
+    }
+
+    #[test]
+    fn test_root_to_uri_str_roundtrip() {
+        let original_uri = format!("pubky://{USER_ID}/pub/pubky.app/");
+        let parsed = ParsedUri::try_from(original_uri.as_str()).unwrap();
+        assert_eq!(parsed.try_to_uri_str().unwrap(), original_uri);
+    }
+
+    #[test]
+    fn test_to_path() {
+        use crate::traits::{HasIdPath, HasPath};
+
+        let post_uri = format!("pubky://{USER_ID}/pub/pubky.app/posts/0032SSN7Q4EVG");
+        let parsed = ParsedUri::try_from(post_uri.as_str()).unwrap();
+        assert_eq!(
+            parsed.to_path().unwrap(),
+            PubkyAppPost::create_path("0032SSN7Q4EVG")
+        );
+        assert!(parsed
+            .try_to_uri_str()
+            .unwrap()
+            .ends_with(&parsed.to_path().unwrap()));
+
+        let profile = ParsedUri::try_from(user_uri_builder(USER_ID.into()).as_str()).unwrap();
+        assert_eq!(profile.to_path().unwrap(), PubkyAppUser::create_path());
+
+        let unknown = ParsedUri {
+            user_id: PubkyId::try_from(USER_ID).unwrap(),
+            resource: Resource::Unknown(None),
+        };
+        assert!(unknown.to_path().is_err());
     }
 
     #[test]
@@ -337,7 +503,74 @@ mod tests {
         let uri = format!("pubky://{USER_ID}/pub/pubky.app/unknown/xyz");
         let parsed = ParsedUri::try_from(uri).expect("Failed to parse URI with unknown resource");
         assert_eq!(parsed.user_id, PubkyId::try_from(USER_ID).unwrap());
-        assert_eq!(parsed.resource, Resource::Unknown);
+        assert_eq!(parsed.resource, Resource::Unknown(Some("unknown".into())));
+    }
+
+    #[test]
+    fn test_unknown_resource_keeps_segment() {
+        let base = format!("pubky://{USER_ID}/pub/pubky.app");
+
+        for path in ["notifications", "notifications/0032SSN7Q4EVG"] {
+            let parsed = ParsedUri::try_from(format!("{base}/{path}")).unwrap();
+            assert_eq!(
+                parsed.resource,
+                Resource::Unknown(Some("notifications".into())),
+                "{path}"
+            );
+            assert_eq!(parsed.resource.kind(), ResourceKind::Unknown);
+            assert_eq!(parsed.resource.id(), None);
+            assert_eq!(parsed.resource.to_string(), "unknown (notifications)");
+        }
+
+        // No resource-type segment to keep
+        let parsed = ParsedUri::try_from(format!("{base}//posts/0032SSN7Q4EVG")).unwrap();
+        assert_eq!(parsed.resource, Resource::Unknown(None));
+        assert_eq!(parsed.resource.to_string(), "unknown");
+        assert_eq!(Resource::default(), Resource::Unknown(None));
+
+        let err =
+            crate::PubkyAppObject::from_uri(format!("{base}/notifications/1"), b"{}").unwrap_err();
+        assert_eq!(err, "Unrecognized resource: unknown (notifications)");
+    }
+
+    #[test]
+    fn test_deserialize_validates_user_id() {
+        let valid = format!(r#"{{"user_id": "{USER_ID}", "resource": {{"User": null}}}}"#);
+        let parsed: ParsedUri = serde_json::from_str(&valid).unwrap();
+        assert_eq!(parsed.user_id, PubkyId::try_from(USER_ID).unwrap());
+
+        let too_short = r#"{"user_id": "garbage", "resource": {"User": null}}"#;
+        assert!(serde_json::from_str::<ParsedUri>(too_short).is_err());
+
+        let follow_with_bad_id =
+            format!(r#"{{"user_id": "{USER_ID}", "resource": {{"Follow": "garbage"}}}}"#);
+        assert!(serde_json::from_str::<ParsedUri>(&follow_with_bad_id).is_err());
+    }
+
+    #[test]
+    fn test_trailing_and_double_slashes() {
+        let base = format!("pubky://{USER_ID}/pub/pubky.app");
+
+        // A single trailing slash after the id is tolerated
+        let parsed = ParsedUri::try_from(format!("{base}/posts/0032SSN7Q4EVG/")).unwrap();
+        assert_eq!(parsed.resource, Resource::Post("0032SSN7Q4EVG".to_string()));
+
+        for path in [
+            // Empty id
+            "posts/",
+            "posts//",
+            // Double slash: the id segment is empty, `0032SSN7Q4EVG` is not the id
+            "posts//0032SSN7Q4EVG",
+            "/posts/0032SSN7Q4EVG",
+            // Extra segments after the id
+            "posts/0032SSN7Q4EVG/extra",
+            "posts/0032SSN7Q4EVG//",
+            // Identifier-less routes don't take a trailing slash
+            "profile.json/",
+        ] {
+            let parsed = ParsedUri::try_from(format!("{base}/{path}")).unwrap();
+            assert!(matches!(parsed.resource, Resource::Unknown(_)), "{path}");
+        }
     }
 
     // Failure cases
@@ -392,6 +625,34 @@ mod tests {
         assert_eq!(original_uri, reconstructed_uri, "User URI roundtrip failed");
     }
 
+    #[test]
+    fn test_locale_profile_uri() {
+        let base = format!("pubky://{USER_ID}/pub/pubky.app");
+
+        let default = ParsedUri::try_from(format!("{base}/profile.json")).unwrap();
+        assert_eq!(default.resource, Resource::User(None));
+
+        for locale in ["pt", "pt-BR"] {
+            let uri = format!("{base}/profile.{locale}.json");
+            let parsed = ParsedUri::try_from(uri.as_str()).unwrap();
+            assert_eq!(parsed.resource, Resource::User(Some(locale.into())));
+            assert_eq!(parsed.resource.kind(), ResourceKind::User);
+            assert_eq!(parsed.resource.id(), None);
+            assert_eq!(parsed.try_to_uri_str().unwrap(), uri);
+        }
+
+        // Malformed locales are not profiles
+        for segment in [
+            "profile..json",
+            "profile.p.json",
+            "profile.-pt.json",
+            "profile.p_t.json",
+        ] {
+            let parsed = ParsedUri::try_from(format!("{base}/{segment}")).unwrap();
+            assert_eq!(parsed.resource, Resource::Unknown(Some(segment.into())));
+        }
+    }
+
     #[test]
     fn test_last_read_uri_roundtrip() {
         let original_uri = last_read_uri_builder(USER_ID.into());
@@ -503,11 +764,64 @@ mod tests {
     fn test_unknown_resource_to_uri_str_fails() {
         let uri = format!("pubky://{USER_ID}/pub/pubky.app/unknown/xyz");
         let parsed = ParsedUri::try_from(uri).expect("Failed to parse URI with unknown resource");
-        assert_eq!(parsed.resource, Resource::Unknown);
+        assert_eq!(parsed.resource, Resource::Unknown(Some("unknown".into())));
         let result = parsed.try_to_uri_str();
         assert!(
             result.is_err(),
             "Unknown resource should fail to convert to URI string"
         );
     }
+
+    #[test]
+    fn test_author_profile_uri() {
+        let uri = post_uri_builder(USER_ID.into(), "0032SSN7Q4EVG".into());
+        let parsed = ParsedUri::try_from(uri).unwrap();
+        assert_eq!(
+            parsed.author_profile_uri(),
+            user_uri_builder(USER_ID.into())
+        );
+
+        let unknown = ParsedUri {
+            user_id: PubkyId::try_from(USER_ID).unwrap(),
+            resource: Resource::Unknown(None),
+        };
+        assert_eq!(
+            unknown.author_profile_uri(),
+            user_uri_builder(USER_ID.into())
+        );
+    }
+
+    #[cfg(feature = "openapi")]
+    #[test]
+    fn test_openapi_schemas() {
+        use utoipa::PartialSchema;
+
+        for schema in [PubkyId::schema(), Resource::schema(), ParsedUri::schema()] {
+            assert!(serde_json::to_value(schema).unwrap().is_object());
+        }
+        let pubky_id = serde_json::to_value(PubkyId::schema()).unwrap();
+        assert_eq!(pubky_id["minLength"], 52);
+    }
+
+    #[test]
+    fn test_resource_kind_as_hash_map_key() {
+        use std::collections::{HashMap, HashSet};
+
+        let uris = [
+            post_uri_builder(USER_ID.into(), "0032SSN7Q4EVG".into()),
+            post_uri_builder(USER_ID.into(), "0032SSN7Q4EVH".into()),
+            follow_uri_builder(USER_ID.into(), USER_ID.into()),
+        ];
+        let mut counts: HashMap<ResourceKind, usize> = HashMap::new();
+        let mut resources = HashSet::new();
+        for uri in uris.iter().chain(uris.iter()) {
+            let parsed = ParsedUri::try_from(uri.as_str()).unwrap();
+            *counts.entry(parsed.resource.kind()).or_default() += 1;
+            resources.insert(parsed.resource);
+        }
+
+        assert_eq!(counts[&ResourceKind::Post], 4);
+        assert_eq!(counts[&ResourceKind::Follow], 2);
+        assert_eq!(resources.len(), 3);
+    }
 }
@@ -8,6 +8,42 @@ use crate::{
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
+/// Cheaply checks whether `s` uses the `pubky://` scheme (case-insensitive),
+/// without parsing the rest of the URI.
+pub fn is_pubky_uri(s: &str) -> bool {
+    s.get(..PROTOCOL.len())
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case(PROTOCOL))
+}
+
+/// Returns the `<user_id>/<path>` remainder of a `pubky://` URI, or `None`
+/// if `s` does not use the `pubky://` scheme.
+pub fn strip_protocol(s: &str) -> Option<&str> {
+    if is_pubky_uri(s) {
+        s.get(PROTOCOL.len()..)
+    } else {
+        None
+    }
+}
+
+/// Returns the app segment that follows `/pub/` in a path or `pubky://` URI,
+/// e.g. `"pubky.app"` for `/pub/pubky.app/posts/0032SSN7Q4EVG`.
+///
+/// A future app version may be written as a suffix on the segment
+/// (e.g. `pubky.app.v2`), so the whole segment is returned. Compare it against
+/// `APP_PATH` to detect objects written by a different app version. Returns
+/// `None` if the path is not under `/pub/pubky.app*`.
+pub fn app_version_of_path(path: &str) -> Option<&str> {
+    let app_name = APP_PATH.trim_end_matches('/');
+    let start = path.find(PUBLIC_PATH)? + PUBLIC_PATH.len();
+    let rest = &path[start..];
+    let segment = rest.split('/').next()?;
+    let suffix = segment.strip_prefix(app_name)?;
+    if suffix.starts_with(|c: char| c.is_alphanumeric()) {
+        return None;
+    }
+    Some(segment)
+}
+
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = baseUriBuilder))]
 pub fn base_uri_builder(user_id: String) -> String {
     format!("{}{}{}{}", PROTOCOL, user_id, PUBLIC_PATH, APP_PATH)
@@ -82,3 +118,53 @@ pub fn last_read_uri_builder(author_id: String) -> String {
     let last_read_path = [PUBLIC_PATH, APP_PATH, "last_read"].concat();
     [PROTOCOL, &author_id, &last_read_path].concat()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_pubky_uri() {
+        assert!(is_pubky_uri("pubky://user_id/pub/pubky.app/profile.json"));
+        assert!(is_pubky_uri("PUBKY://user_id"));
+        assert!(!is_pubky_uri("https://example.com"));
+        assert!(!is_pubky_uri("user_id/pub/pubky.app/profile.json"));
+        assert!(!is_pubky_uri("pubky:"));
+        assert!(!is_pubky_uri(""));
+    }
+
+    #[test]
+    fn test_strip_protocol() {
+        assert_eq!(
+            strip_protocol("pubky://user_id/pub/pubky.app/posts/0032SSN7Q4EVG"),
+            Some("user_id/pub/pubky.app/posts/0032SSN7Q4EVG")
+        );
+        assert_eq!(strip_protocol("pubky://"), Some(""));
+        assert_eq!(strip_protocol("https://example.com"), None);
+        assert_eq!(strip_protocol("user_id/pub/pubky.app/profile.json"), None);
+    }
+
+    #[test]
+    fn test_app_version_of_path() {
+        let current = APP_PATH.trim_end_matches('/');
+        assert_eq!(
+            app_version_of_path(&PubkyAppPost::create_path("0032SSN7Q4EVG")),
+            Some(current)
+        );
+        assert_eq!(
+            app_version_of_path(&user_uri_builder("user_id".into())),
+            Some(current)
+        );
+        assert_eq!(
+            app_version_of_path(&[PUBLIC_PATH, APP_PATH].concat()),
+            Some(current)
+        );
+        assert_eq!(
+            app_version_of_path("/pub/pubky.app.v2/posts/0032SSN7Q4EVG"),
+            Some("pubky.app.v2")
+        );
+        assert_eq!(app_version_of_path("/pub/pubky.apple/posts/x"), None);
+        assert_eq!(app_version_of_path("/pub/other.app/posts/x"), None);
+        assert_eq!(app_version_of_path("/posts/0032SSN7Q4EVG"), None);
+    }
+}
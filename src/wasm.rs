@@ -1,6 +1,7 @@
 use crate::limits::VALIDATION_LIMITS;
 use crate::traits::{HasIdPath, HasPath, HashId, TimestampId, Validatable};
 use crate::*;
+use serde::Serialize;
 use serde_wasm_bindgen::{from_value, to_value};
 use std::str::FromStr;
 use wasm_bindgen::prelude::*;
@@ -19,7 +20,7 @@ pub fn get_validation_limits() -> Result<JsValue, String> {
 }
 
 #[wasm_bindgen]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Meta {
     /// The unique ID for this object (empty if none)
     id: String,
@@ -47,6 +48,12 @@ impl Meta {
     pub fn url(&self) -> String {
         self.url.clone()
     }
+
+    /// Returns `{ id, path, url }` as a plain JS object.
+    #[wasm_bindgen(js_name = toJson)]
+    pub fn to_json(&self) -> Result<JsValue, String> {
+        to_value(self).map_err(|e| format!("JSON serialization error: {}", e))
+    }
 }
 
 impl Meta {
@@ -236,7 +243,7 @@ impl PubkySpecsBuilder {
         name: String,
         src: String,
         content_type: String,
-        size: usize,
+        size: u64,
     ) -> Result<FileResult, String> {
         let file = PubkyAppFile::new(name, src, content_type, size);
         let file_id = file.create_id();
@@ -430,12 +437,75 @@ impl PubkySpecsBuilder {
 
         Ok(BlobResult { blob, meta })
     }
+
+    // -----------------------------------------------------------------------------
+    // Validation of objects received from elsewhere
+    // -----------------------------------------------------------------------------
+    //
+    // Unlike the `create*` functions, these neither build nor sanitize the
+    // object: they run the spec validation on it as-is and return nothing on
+    // success, or a descriptive error.
+
+    #[wasm_bindgen(js_name = validateUser)]
+    pub fn validate_user(&self, user: PubkyAppUser) -> Result<(), String> {
+        user.validate(None)
+    }
+
+    #[wasm_bindgen(js_name = validateFeed)]
+    pub fn validate_feed(&self, feed: PubkyAppFeed, id: String) -> Result<(), String> {
+        feed.validate(Some(&id))
+    }
+
+    #[wasm_bindgen(js_name = validateFile)]
+    pub fn validate_file(&self, file: PubkyAppFile, id: String) -> Result<(), String> {
+        file.validate(Some(&id))
+    }
+
+    #[wasm_bindgen(js_name = validatePost)]
+    pub fn validate_post(&self, post: PubkyAppPost, id: String) -> Result<(), String> {
+        post.validate(Some(&id))
+    }
+
+    #[wasm_bindgen(js_name = validateTag)]
+    pub fn validate_tag(&self, tag: PubkyAppTag, id: String) -> Result<(), String> {
+        tag.validate(Some(&id))
+    }
+
+    #[wasm_bindgen(js_name = validateBookmark)]
+    pub fn validate_bookmark(&self, bookmark: PubkyAppBookmark, id: String) -> Result<(), String> {
+        bookmark.validate(Some(&id))
+    }
+
+    #[wasm_bindgen(js_name = validateFollow)]
+    pub fn validate_follow(
+        &self,
+        follow: PubkyAppFollow,
+        followee_id: String,
+    ) -> Result<(), String> {
+        follow.validate(Some(&followee_id))
+    }
+
+    #[wasm_bindgen(js_name = validateMute)]
+    pub fn validate_mute(&self, mute: PubkyAppMute, mutee_id: String) -> Result<(), String> {
+        mute.validate(Some(&mutee_id))
+    }
+
+    #[wasm_bindgen(js_name = validateLastRead)]
+    pub fn validate_last_read(&self, last_read: PubkyAppLastRead) -> Result<(), String> {
+        last_read.validate(None)
+    }
+
+    #[wasm_bindgen(js_name = validateBlob)]
+    pub fn validate_blob(&self, blob: PubkyAppBlob, id: String) -> Result<(), String> {
+        blob.validate(Some(&id))
+    }
 }
 
 /// This object represents the result of parsing a Pubky URI. It contains:
 /// - `user_id`: the parsed user ID as a string.
 /// - `resource`: a string representing the kind of resource (derived from internal `Resource` enum Display).
 /// - `resource_id`: an optional resource identifier (if applicable).
+/// - `resource_id_kind`: how `resource_id` is formed: `"pubky_id"`, `"timestamp"` or `"hash"`.
 #[wasm_bindgen]
 pub struct ParsedUriResult {
     #[wasm_bindgen(skip)]
@@ -444,6 +514,8 @@ pub struct ParsedUriResult {
     resource: String,
     #[wasm_bindgen(skip)]
     resource_id: Option<String>,
+    #[wasm_bindgen(skip)]
+    resource_id_kind: Option<String>,
 }
 
 #[wasm_bindgen]
@@ -465,6 +537,27 @@ impl ParsedUriResult {
     pub fn resource_id(&self) -> Option<String> {
         self.resource_id.clone()
     }
+
+    /// Returns how the resource ID is formed, if present: `"pubky_id"` for
+    /// follows and mutes, `"timestamp"` for posts and files, `"hash"` for
+    /// bookmarks, tags, blobs and feeds.
+    #[wasm_bindgen(getter)]
+    pub fn resource_id_kind(&self) -> Option<String> {
+        self.resource_id_kind.clone()
+    }
+}
+
+/// Classifies the identifier carried by a resource, for JS consumers that
+/// only see it as a string.
+fn resource_id_kind(resource: &Resource) -> Option<&'static str> {
+    match resource {
+        Resource::Follow(_) | Resource::Mute(_) => Some("pubky_id"),
+        Resource::Post(_) | Resource::File(_) => Some("timestamp"),
+        Resource::Bookmark(_) | Resource::Tag(_) | Resource::Blob(_) | Resource::Feed(_) => {
+            Some("hash")
+        }
+        Resource::User(_) | Resource::LastRead | Resource::Root | Resource::Unknown(_) => None,
+    }
 }
 
 /// Returns the list of valid MIME types for file attachments.
@@ -506,7 +599,8 @@ pub fn get_valid_mime_types() -> Vec<JsValue> {
 /// On success, returns a `ParsedUriResult` with:
 /// - `user_id`: the parsed user ID,
 /// - `resource`: a string (derived from the Display implementation of internal `Resource` enum),
-/// - `resource_id`: an optional resource identifier (if applicable).
+/// - `resource_id`: an optional resource identifier (if applicable),
+/// - `resource_id_kind`: `"pubky_id"`, `"timestamp"` or `"hash"` when `resource_id` is set.
 ///
 /// On failure, returns a JavaScript error (`String`) containing an error message.
 ///
@@ -520,6 +614,7 @@ pub fn get_valid_mime_types() -> Vec<JsValue> {
 ///   console.log(result.user_id);        // e.g. "user123"
 ///   console.log(result.resource);    // e.g. "posts"
 ///   console.log(result.resource_id);      // e.g. "abc123" or null
+///   console.log(result.resource_id_kind); // e.g. "timestamp" or null
 /// } catch (error) {
 ///   console.error("Error parsing URI:", error);
 /// }
@@ -534,5 +629,6 @@ pub fn parse_uri(uri: &str) -> Result<ParsedUriResult, String> {
         user_id: parsed.user_id.to_string(),
         resource: parsed.resource.to_string(),
         resource_id: parsed.resource.id(),
+        resource_id_kind: resource_id_kind(&parsed.resource).map(String::from),
     })
 }
@@ -4,8 +4,9 @@ extern crate wasm_bindgen_test;
 use js_sys::Array;
 use pubky_app_specs::traits::{HasIdPath, HasPath};
 use pubky_app_specs::{
-    follow_uri_builder, parse_uri, post_uri_builder, user_uri_builder, PubkyAppFollow,
-    PubkyAppPost, PubkyAppPostKind, PubkyAppUser, PubkyAppUserLink, PubkySpecsBuilder,
+    follow_uri_builder, last_read_uri_builder, parse_uri, post_uri_builder, user_uri_builder,
+    PubkyAppFollow, PubkyAppLastRead, PubkyAppPost, PubkyAppPostKind, PubkyAppUser,
+    PubkyAppUserLink, PubkySpecsBuilder,
 };
 use serde_wasm_bindgen::to_value;
 use wasm_bindgen::JsValue;
@@ -44,6 +45,27 @@ fn test_create_follow() {
     assert!(follow.created_at > 0);
 }
 
+#[wasm_bindgen_test]
+fn test_meta_serializes() {
+    let user_id = "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo";
+    let specs = PubkySpecsBuilder::new(user_id.to_string()).expect("Valid pubky ID");
+    let meta = specs
+        .create_follow(user_id.to_string())
+        .expect("create_follow should not fail")
+        .meta();
+
+    let expected = serde_json::json!({
+        "id": user_id,
+        "path": PubkyAppFollow::create_path(user_id),
+        "url": follow_uri_builder(user_id.into(), user_id.into()),
+    });
+    assert_eq!(serde_json::to_value(&meta).unwrap(), expected);
+
+    let js_meta: serde_json::Value =
+        serde_wasm_bindgen::from_value(meta.to_json().expect("toJson should not fail")).unwrap();
+    assert_eq!(js_meta, expected);
+}
+
 #[wasm_bindgen_test]
 fn test_create_user_rust_api() {
     let specs =
@@ -205,4 +227,80 @@ fn test_parse_uri() {
         "0032SSN7Q4EVG",
         "The resource_id should match the post id provided in the URI"
     );
+    assert_eq!(parsed.resource_id_kind().as_deref(), Some("timestamp"));
+}
+
+#[wasm_bindgen_test]
+fn test_parse_uri_follow_resource_id_kind() {
+    let followee = "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo";
+    let uri = follow_uri_builder(
+        "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".into(),
+        followee.into(),
+    );
+    let parsed = parse_uri(&uri).expect("Expected valid follow URI parsing");
+
+    assert_eq!(parsed.resource(), "follows");
+    assert_eq!(parsed.resource_id().as_deref(), Some(followee));
+    assert_eq!(parsed.resource_id_kind().as_deref(), Some("pubky_id"));
+
+    let profile = parse_uri(&user_uri_builder(
+        "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".into(),
+    ))
+    .expect("Expected valid profile URI parsing");
+    assert_eq!(profile.resource_id_kind(), None);
+}
+
+#[wasm_bindgen_test]
+fn test_parse_uri_without_resource_id() {
+    let user_id = "operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo";
+
+    let last_read = parse_uri(&last_read_uri_builder(user_id.into()))
+        .expect("Expected valid last_read URI parsing");
+    assert_eq!(last_read.user_id(), user_id);
+    assert_eq!(
+        last_read.resource(),
+        PubkyAppLastRead::PATH_SEGMENT.trim_end_matches('/')
+    );
+    assert_eq!(last_read.resource_id(), None);
+
+    let profile =
+        parse_uri(&user_uri_builder(user_id.into())).expect("Expected valid profile URI parsing");
+    assert_eq!(profile.user_id(), user_id);
+    assert_eq!(
+        profile.resource(),
+        PubkyAppUser::PATH_SEGMENT.trim_end_matches('/')
+    );
+    assert_eq!(profile.resource_id(), None);
+}
+
+#[wasm_bindgen_test]
+fn test_validate_post() {
+    let specs =
+        PubkySpecsBuilder::new("operrr8wsbpr3ue9d4qj41ge1kcc6r7fdiy6o3ugjrrhi4y77rdo".to_string())
+            .expect("Valid pubky ID");
+
+    // A post created elsewhere and received as-is
+    let result = specs
+        .create_post(
+            "Hello from elsewhere".to_string(),
+            PubkyAppPostKind::Short,
+            None,
+            None,
+            None,
+        )
+        .expect("create_post should not fail");
+    let post_id = result.meta().id();
+    specs
+        .validate_post(result.post(), post_id.clone())
+        .expect("A freshly created post should validate");
+
+    // A post with no content, embed or attachments is rejected
+    let empty_post = PubkyAppPost {
+        content: "".to_string(),
+        ..result.post()
+    };
+    let err = specs
+        .validate_post(empty_post, post_id)
+        .expect_err("An empty post should not validate");
+    assert!(err.contains("must have content"));
 }